@@ -48,6 +48,9 @@ use std::mem::size_of;
 #[derive(Debug)]
 pub struct PyByteArray {
     inner: PyRwLock<PyBytesInner>,
+    /// Number of live `PyBuffer` exports (e.g. `memoryview`s); `try_resizable`
+    /// refuses to resize while this is nonzero. Decremented on each export's
+    /// release, in any order, so resizing re-enables once the last one drops.
     exports: AtomicCell<usize>,
 }
 
@@ -663,11 +666,7 @@ impl BufferProtocol for PyByteArray {
         zelf.exports.fetch_add(1);
         let buf = ByteArrayBuffer {
             bytearray: zelf.clone(),
-            options: BufferOptions {
-                readonly: false,
-                len: zelf.len(),
-                ..Default::default()
-            },
+            options: BufferOptions::simple_rw(zelf.len()),
         };
         Ok(Box::new(buf))
     }
@@ -730,8 +729,8 @@ impl Iterable for PyByteArray {
 #[pyclass(module = false, name = "bytearray_iterator")]
 #[derive(Debug)]
 pub struct PyByteArrayIterator {
-    position: AtomicCell<usize>,
-    bytearray: PyByteArrayRef,
+    pub(crate) position: AtomicCell<usize>,
+    pub(crate) bytearray: PyByteArrayRef,
 }
 
 impl PyValue for PyByteArrayIterator {
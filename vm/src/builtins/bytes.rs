@@ -498,10 +498,7 @@ impl BufferProtocol for PyBytes {
     fn get_buffer(zelf: &PyRef<Self>, _vm: &VirtualMachine) -> PyResult<Box<dyn Buffer>> {
         let buf = BytesBuffer {
             bytes: zelf.clone(),
-            options: BufferOptions {
-                len: zelf.len(),
-                ..Default::default()
-            },
+            options: BufferOptions::simple_ro(zelf.len()),
         };
         Ok(Box::new(buf))
     }
@@ -573,8 +570,8 @@ impl Iterable for PyBytes {
 #[pyclass(module = false, name = "bytes_iterator")]
 #[derive(Debug)]
 pub struct PyBytesIterator {
-    position: AtomicCell<usize>,
-    bytes: PyBytesRef,
+    pub(crate) position: AtomicCell<usize>,
+    pub(crate) bytes: PyBytesRef,
 }
 
 impl PyValue for PyBytesIterator {
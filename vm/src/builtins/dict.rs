@@ -4,7 +4,7 @@ use std::mem::size_of;
 
 use super::pystr::PyStrRef;
 use super::pytype::PyTypeRef;
-use super::set::PySet;
+use super::set::{self, PySet};
 use crate::dictdatatype::{self, DictKey};
 use crate::exceptions::PyBaseExceptionRef;
 use crate::function::{FuncArgs, KwArgs, OptionalArg};
@@ -74,37 +74,7 @@ impl PyDict {
         vm: &VirtualMachine,
     ) -> PyResult<()> {
         if let OptionalArg::Present(dict_obj) = dict_obj {
-            let dicted: Result<PyDictRef, _> = dict_obj.clone().downcast();
-            if let Ok(dict_obj) = dicted {
-                for (key, value) in dict_obj {
-                    dict.insert(vm, key, value)?;
-                }
-            } else if let Some(keys) = vm.get_method(dict_obj.clone(), "keys") {
-                let keys = iterator::get_iter(vm, vm.invoke(&keys?, ())?)?;
-                while let Some(key) = iterator::get_next_object(vm, &keys)? {
-                    let val = dict_obj.get_item(key.clone(), vm)?;
-                    dict.insert(vm, key, val)?;
-                }
-            } else {
-                let iter = iterator::get_iter(vm, dict_obj)?;
-                loop {
-                    fn err(vm: &VirtualMachine) -> PyBaseExceptionRef {
-                        vm.new_value_error("Iterator must have exactly two elements".to_owned())
-                    }
-                    let element = match iterator::get_next_object(vm, &iter)? {
-                        Some(obj) => obj,
-                        None => break,
-                    };
-                    let elem_iter = iterator::get_iter(vm, element)?;
-                    let key = iterator::get_next_object(vm, &elem_iter)?.ok_or_else(|| err(vm))?;
-                    let value =
-                        iterator::get_next_object(vm, &elem_iter)?.ok_or_else(|| err(vm))?;
-                    if iterator::get_next_object(vm, &elem_iter)?.is_some() {
-                        return Err(err(vm));
-                    }
-                    dict.insert(vm, key, value)?;
-                }
-            }
+            PyMapping::merge_into(dict_obj, dict, vm)?;
         }
 
         for (key, value) in kwargs.into_iter() {
@@ -386,6 +356,8 @@ impl PyDict {
         self.entries.size()
     }
 
+    // `OrderedDict` (in `Lib/collections/__init__.py`) has its own
+    // `__reversed__`, so this walker is only for plain `dict` and its views.
     #[pymethod(name = "__reversed__")]
     fn reversed(zelf: PyRef<Self>) -> PyDictReverseKeyIterator {
         PyDictReverseKeyIterator::new(zelf)
@@ -586,11 +558,26 @@ impl Iterator for DictIter {
     }
 }
 
+/// Shared by the forward and reverse variants of each `dict_iterator!`
+/// iterator: picks up `remaining`, the already-direction-ordered, already
+/// `$result_fn`-mapped items left to yield, and produces the
+/// `(iter, (list,))` reduce tuple CPython's own dict iterators use --
+/// unlike `next()`, this detaches the iterator from the live dict, so
+/// further mutation of the original dict after pickling doesn't affect
+/// what round-trips back out.
+fn reduce_dict_iter(remaining: Vec<PyObjectRef>, vm: &VirtualMachine) -> PyResult {
+    let iter = vm.get_attribute(vm.builtins.clone(), "iter")?;
+    Ok(vm.ctx.new_tuple(vec![
+        iter,
+        vm.ctx.new_tuple(vec![vm.ctx.new_list(remaining)]),
+    ]))
+}
+
 macro_rules! dict_iterator {
     ( $name: ident, $iter_name: ident, $reverse_iter_name: ident,
       $class: ident, $iter_class: ident, $reverse_iter_class: ident,
       $class_name: literal, $iter_class_name: literal, $reverse_iter_class_name: literal,
-      $result_fn: expr) => {
+      $result_fn: expr, { $($extra:item)* }) => {
         #[pyclass(module=false,name = $class_name)]
         #[derive(Debug)]
         pub(crate) struct $name {
@@ -627,6 +614,8 @@ macro_rules! dict_iterator {
             fn reversed(&self) -> $reverse_iter_name {
                 $reverse_iter_name::new(self.dict.clone())
             }
+
+            $($extra)*
         }
 
         impl Iterable for $name {
@@ -697,6 +686,17 @@ macro_rules! dict_iterator {
             fn length_hint(&self) -> usize {
                 self.dict.entries.len_from_entry_index(self.position.load())
             }
+
+            #[pymethod(magic)]
+            #[allow(clippy::redundant_closure_call)]
+            fn reduce(&self, vm: &VirtualMachine) -> PyResult {
+                let mut position = self.position.load();
+                let mut remaining = Vec::new();
+                while let Some((key, value)) = self.dict.entries.next_entry(&mut position) {
+                    remaining.push(($result_fn)(vm, key, value));
+                }
+                reduce_dict_iter(remaining, vm)
+            }
         }
 
         impl PyIter for $iter_name {
@@ -746,6 +746,19 @@ macro_rules! dict_iterator {
             fn length_hint(&self) -> usize {
                 self.dict.entries.len_from_entry_index(self.position.load())
             }
+
+            #[pymethod(magic)]
+            #[allow(clippy::redundant_closure_call)]
+            fn reduce(&self, vm: &VirtualMachine) -> PyResult {
+                let mut count = self.position.load();
+                let mut remaining = Vec::new();
+                while let Some(mut pos) = self.dict.len().checked_sub(count) {
+                    let (key, value) = self.dict.entries.next_entry(&mut pos).unwrap();
+                    remaining.push(($result_fn)(vm, key, value));
+                    count += 1;
+                }
+                reduce_dict_iter(remaining, vm)
+            }
         }
 
         impl PyIter for $reverse_iter_name {
@@ -782,7 +795,73 @@ dict_iterator! {
     "dict_keys",
     "dict_keyiterator",
     "dict_reversekeyiterator",
-    |_vm: &VirtualMachine, key: PyObjectRef, _value: PyObjectRef| key
+    |_vm: &VirtualMachine, key: PyObjectRef, _value: PyObjectRef| key,
+    {
+        // `self.dict` is a hash table, so membership is O(1) per item --
+        // iterate `other` (it might be a one-shot iterable) and look each
+        // item up directly, stopping at the first match.
+        #[pymethod]
+        fn isdisjoint(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<bool> {
+            for item in other.iter(vm)? {
+                if zelf.dict.entries.contains(vm, &item?)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+
+        // Set-algebra operators: unlike `PySet`'s own operators, which reject
+        // a non-set operand outright, these accept any iterable on either
+        // side -- matching `dictviews_and`/`_or`/`_xor`/`_sub` in CPython's
+        // `Objects/dictobject.c` -- and always produce a plain `set`.
+        #[pymethod(name = "__and__")]
+        fn and(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_and(lhs, other, vm)
+        }
+
+        #[pymethod(name = "__rand__")]
+        fn rand(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            Self::and(zelf, other, vm)
+        }
+
+        #[pymethod(name = "__or__")]
+        fn or(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_or(lhs, other, vm)
+        }
+
+        #[pymethod(name = "__ror__")]
+        fn ror(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            Self::or(zelf, other, vm)
+        }
+
+        #[pymethod(name = "__xor__")]
+        fn xor(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_xor(lhs, other, vm)
+        }
+
+        #[pymethod(name = "__rxor__")]
+        fn rxor(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            Self::xor(zelf, other, vm)
+        }
+
+        #[pymethod(name = "__sub__")]
+        fn sub(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_sub(lhs, other, vm)
+        }
+
+        // Not symmetric with `sub`: `other.__rsub__(view)` means `other -
+        // view`, so the view ends up as the subtrahend here instead of the
+        // minuend.
+        #[pymethod(name = "__rsub__")]
+        fn rsub(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let rhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_sub(other, rhs, vm)
+        }
+    }
 }
 
 dict_iterator! {
@@ -795,7 +874,26 @@ dict_iterator! {
     "dict_values",
     "dict_valueiterator",
     "dict_reversevalueiterator",
-    |_vm: &VirtualMachine, _key: PyObjectRef, value: PyObjectRef| value
+    |_vm: &VirtualMachine, _key: PyObjectRef, value: PyObjectRef| value,
+    {
+        // Unlike `dict_keys`/`dict_items`, `dict_values` isn't set-like (see
+        // the set-algebra operators above), so `in` can't just hash the
+        // needle and look it up -- it has to scan. The default
+        // iteration-based fallback (`VirtualMachine::_membership`) would get
+        // this wrong for values that don't equal themselves (e.g. `nan`),
+        // since it compares with `bool_eq` alone; `identical_or_equal`'s
+        // identity shortcut matches CPython's `PySequence_Contains`, which
+        // treats an object as contained in itself even when `==` says no.
+        #[pymethod(magic)]
+        fn contains(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult<bool> {
+            for (_, value) in self.dict.clone() {
+                if vm.identical_or_equal(&needle, &value)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+    }
 }
 
 dict_iterator! {
@@ -809,7 +907,82 @@ dict_iterator! {
     "dict_itemiterator",
     "dict_reverseitemiterator",
     |vm: &VirtualMachine, key: PyObjectRef, value: PyObjectRef|
-        vm.ctx.new_tuple(vec![key, value])
+        vm.ctx.new_tuple(vec![key, value]),
+    {
+        // Unlike `PyDictKeys::isdisjoint`, a lookup against `self.dict` alone
+        // isn't enough here -- a `(key, value)` pair is only "in" the items
+        // view if the value matches too -- so each candidate pair from
+        // `other` is unpacked and compared against the dict's actual value
+        // for that key.
+        #[pymethod]
+        fn isdisjoint(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<bool> {
+            fn err(vm: &VirtualMachine) -> PyBaseExceptionRef {
+                vm.new_value_error("Iterator must have exactly two elements".to_owned())
+            }
+            for item in other.iter(vm)? {
+                let pair = iterator::get_iter(vm, item?)?;
+                let key = iterator::get_next_object(vm, &pair)?.ok_or_else(|| err(vm))?;
+                let value = iterator::get_next_object(vm, &pair)?.ok_or_else(|| err(vm))?;
+                if iterator::get_next_object(vm, &pair)?.is_some() {
+                    return Err(err(vm));
+                }
+                if let Some(existing) = zelf.dict.get_item_option(key, vm)? {
+                    if vm.bool_eq(&existing, &value)? {
+                        return Ok(false);
+                    }
+                }
+            }
+            Ok(true)
+        }
+
+        #[pymethod(name = "__and__")]
+        fn and(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_and(lhs, other, vm)
+        }
+
+        #[pymethod(name = "__rand__")]
+        fn rand(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            Self::and(zelf, other, vm)
+        }
+
+        #[pymethod(name = "__or__")]
+        fn or(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_or(lhs, other, vm)
+        }
+
+        #[pymethod(name = "__ror__")]
+        fn ror(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            Self::or(zelf, other, vm)
+        }
+
+        #[pymethod(name = "__xor__")]
+        fn xor(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_xor(lhs, other, vm)
+        }
+
+        #[pymethod(name = "__rxor__")]
+        fn rxor(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            Self::xor(zelf, other, vm)
+        }
+
+        #[pymethod(name = "__sub__")]
+        fn sub(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let lhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_sub(lhs, other, vm)
+        }
+
+        // Not symmetric with `sub`: `other.__rsub__(view)` means `other -
+        // view`, so the view ends up as the subtrahend here instead of the
+        // minuend.
+        #[pymethod(name = "__rsub__")]
+        fn rsub(zelf: PyRef<Self>, other: PyIterable, vm: &VirtualMachine) -> PyResult<PySet> {
+            let rhs = PyIterable::try_from_object(vm, zelf.into_object())?;
+            set::dict_view_sub(other, rhs, vm)
+        }
+    }
 }
 
 pub(crate) fn init(context: &PyContext) {
@@ -849,4 +1022,105 @@ impl PyMapping {
     pub fn into_dict(self) -> PyDictRef {
         self.dict
     }
+
+    /// Copies `source`'s pairs into `target`, implementing the `keys()`
+    /// protocol shared by `dict(mapping)` and `dict.update(mapping)`: if
+    /// `source` is a `dict` its entries are inserted directly; otherwise, if
+    /// it has a `keys()` method (so any mapping-like object, not just a real
+    /// `dict`, qualifies), each key is fetched via `keys()` and subscripted
+    /// with `__getitem__`; otherwise `source` is treated as a plain iterable
+    /// of `(key, value)` pairs.
+    pub fn merge_into(
+        source: PyObjectRef,
+        target: &DictContentType,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let dicted: Result<PyDictRef, _> = source.clone().downcast();
+        if let Ok(source) = dicted {
+            for (key, value) in source {
+                target.insert(vm, key, value)?;
+            }
+        } else if let Some(keys) = vm.get_method(source.clone(), "keys") {
+            let keys = iterator::get_iter(vm, vm.invoke(&keys?, ())?)?;
+            while let Some(key) = iterator::get_next_object(vm, &keys)? {
+                let val = source.get_item(key.clone(), vm)?;
+                target.insert(vm, key, val)?;
+            }
+        } else {
+            let iter = iterator::get_iter(vm, source)?;
+            loop {
+                fn err(vm: &VirtualMachine) -> PyBaseExceptionRef {
+                    vm.new_value_error("Iterator must have exactly two elements".to_owned())
+                }
+                let element = match iterator::get_next_object(vm, &iter)? {
+                    Some(obj) => obj,
+                    None => break,
+                };
+                let elem_iter = iterator::get_iter(vm, element)?;
+                let key = iterator::get_next_object(vm, &elem_iter)?.ok_or_else(|| err(vm))?;
+                let value = iterator::get_next_object(vm, &elem_iter)?.ok_or_else(|| err(vm))?;
+                if iterator::get_next_object(vm, &elem_iter)?.is_some() {
+                    return Err(err(vm));
+                }
+                target.insert(vm, key, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Unlike `PyMapping::try_from_object`, which always eagerly builds a
+    /// whole `dict` out of `mapping` (needed by callers like
+    /// `_winapi.CreateProcess`'s `env_mapping` that index back into it), this
+    /// calls `mapping.items()` once and hands back a lazy iterator over the
+    /// result, for callers that only ever walk the pairs once and shouldn't
+    /// pay to materialize a `dict` (or a `list`, for a plain iterable of
+    /// pairs) they never look anything up in.
+    pub fn iter_items(mapping: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let items = vm.call_method(&mapping, "items", ())?;
+        iterator::get_iter(vm, items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtins::{int, list::PyList};
+    use crate::compile;
+    use crate::Interpreter;
+
+    #[test]
+    fn iter_items_does_not_materialize_a_list() {
+        Interpreter::default().enter(|vm| {
+            let source = "
+class LazyMapping:
+    def __init__(self):
+        self.pulled = 0
+
+    def items(self):
+        def gen():
+            for i in range(3):
+                self.pulled += 1
+                yield (i, i * i)
+        return gen()
+
+m = LazyMapping()
+";
+            let scope = vm.new_scope_with_builtins();
+            let code = vm
+                .compile(source, compile::Mode::Exec, "<test>".to_owned())
+                .unwrap();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let mapping = scope.locals.as_object().get_item("m", vm).unwrap();
+
+            let iter = PyMapping::iter_items(mapping.clone(), vm).unwrap();
+            assert!(!iter.payload_is::<PyList>());
+
+            let pulled = || vm.get_attribute(mapping.clone(), "pulled").unwrap();
+            assert_eq!(int::get_value(&pulled()).to_string(), "0");
+
+            let first = iterator::call_next(vm, &iter).unwrap();
+            assert_eq!(vm.to_repr(&first).unwrap().as_str(), "(0, 0)");
+            assert_eq!(int::get_value(&pulled()).to_string(), "1");
+        })
+    }
 }
@@ -1,12 +1,16 @@
+use crossbeam_utils::atomic::AtomicCell;
+
 use crate::common::lock::PyRwLock;
 
 use num_bigint::BigInt;
 use num_traits::Zero;
 
-use super::int::PyIntRef;
+use super::bytearray::{PyByteArrayIterator, PyByteArrayRef};
+use super::bytes::{PyBytesIterator, PyBytesRef};
 use super::pytype::PyTypeRef;
 use crate::function::OptionalArg;
 use crate::iterator;
+use crate::pyobject::{IdProtocol, TypeProtocol};
 use crate::slots::PyIter;
 use crate::vm::VirtualMachine;
 use crate::{IntoPyObject, PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue};
@@ -15,7 +19,74 @@ use crate::{IntoPyObject, PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult,
 #[derive(Debug)]
 pub struct PyEnumerate {
     counter: PyRwLock<BigInt>,
-    iterator: PyObjectRef,
+    iterable: EnumerateIterable,
+}
+
+/// The source `enumerate` pulls values from: a generic Python iterator for
+/// anything else, or -- to avoid materializing an intermediate
+/// `bytes_iterator`/`bytearray_iterator` object -- the sequence itself plus
+/// a position, for a concrete (non-subclassed) `bytes`/`bytearray`.
+#[derive(Debug)]
+enum EnumerateIterable {
+    Generic(PyObjectRef),
+    Bytes(PyBytesRef, AtomicCell<usize>),
+    ByteArray(PyByteArrayRef, AtomicCell<usize>),
+}
+
+impl EnumerateIterable {
+    fn next(&self, vm: &VirtualMachine) -> PyResult {
+        match self {
+            EnumerateIterable::Generic(iterator) => iterator::call_next(vm, iterator),
+            EnumerateIterable::Bytes(bytes, position) => {
+                let pos = position.fetch_add(1);
+                bytes
+                    .as_bytes()
+                    .get(pos)
+                    .map(|&byte| vm.ctx.new_int(byte))
+                    .ok_or_else(|| vm.new_stop_iteration())
+            }
+            EnumerateIterable::ByteArray(bytearray, position) => {
+                let pos = position.fetch_add(1);
+                bytearray
+                    .borrow_buf()
+                    .get(pos)
+                    .map(|&byte| byte.into_pyobject(vm))
+                    .ok_or_else(|| vm.new_stop_iteration())
+            }
+        }
+    }
+
+    fn length_hint(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        Ok(match self {
+            EnumerateIterable::Generic(iterator) => {
+                iterator::length_hint(vm, iterator.clone())?.unwrap_or(0)
+            }
+            EnumerateIterable::Bytes(bytes, position) => {
+                bytes.as_bytes().len().saturating_sub(position.load())
+            }
+            EnumerateIterable::ByteArray(bytearray, position) => {
+                bytearray.borrow_buf().len().saturating_sub(position.load())
+            }
+        })
+    }
+
+    /// Reconstructs the plain Python iterator `__reduce__` expects, picking
+    /// up from wherever this iterable has gotten to.
+    fn to_iterator_object(&self, vm: &VirtualMachine) -> PyObjectRef {
+        match self {
+            EnumerateIterable::Generic(iterator) => iterator.clone(),
+            EnumerateIterable::Bytes(bytes, position) => PyBytesIterator {
+                position: AtomicCell::new(position.load()),
+                bytes: bytes.clone(),
+            }
+            .into_object(vm),
+            EnumerateIterable::ByteArray(bytearray, position) => PyByteArrayIterator {
+                position: AtomicCell::new(position.load()),
+                bytearray: bytearray.clone(),
+            }
+            .into_object(vm),
+        }
+    }
 }
 
 impl PyValue for PyEnumerate {
@@ -29,7 +100,7 @@ struct EnumerateArgs {
     #[pyarg(any)]
     iterable: PyObjectRef,
     #[pyarg(any, optional)]
-    start: OptionalArg<PyIntRef>,
+    start: OptionalArg<PyObjectRef>,
 }
 
 #[pyimpl(with(PyIter))]
@@ -37,22 +108,50 @@ impl PyEnumerate {
     #[pyslot]
     fn tp_new(cls: PyTypeRef, args: EnumerateArgs, vm: &VirtualMachine) -> PyResult<PyRef<Self>> {
         let counter = match args.start {
-            OptionalArg::Present(start) => start.as_bigint().clone(),
+            OptionalArg::Present(start) => vm.to_index(&start)?.as_bigint().clone(),
             OptionalArg::Missing => BigInt::zero(),
         };
 
-        let iterator = iterator::get_iter(vm, args.iterable)?;
+        // Exact (non-subclassed) `bytes`/`bytearray` always yield plain ints
+        // from iteration, so they can be walked directly instead of routing
+        // every `next()` through a `bytes_iterator`/`bytearray_iterator`
+        // object and the generic iterator protocol. Subclasses may override
+        // `__iter__`/`__getitem__`, so they still go through the slow path.
+        let is_bytes = args.iterable.class().is(&vm.ctx.types.bytes_type);
+        let is_bytearray = args.iterable.class().is(&vm.ctx.types.bytearray_type);
+        let iterable = if is_bytes {
+            EnumerateIterable::Bytes(args.iterable.downcast().unwrap(), AtomicCell::new(0))
+        } else if is_bytearray {
+            EnumerateIterable::ByteArray(args.iterable.downcast().unwrap(), AtomicCell::new(0))
+        } else {
+            EnumerateIterable::Generic(iterator::get_iter(vm, args.iterable)?)
+        };
+
         PyEnumerate {
             counter: PyRwLock::new(counter),
-            iterator,
+            iterable,
         }
         .into_ref_with_type(vm, cls)
     }
+
+    #[pymethod(name = "__length_hint__")]
+    fn length_hint(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        self.iterable.length_hint(vm)
+    }
+
+    #[pymethod(magic)]
+    fn reduce(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyObjectRef {
+        let cls = zelf.as_object().clone_class().into_object();
+        let counter = vm.ctx.new_int(zelf.counter.read().clone());
+        let iterator = zelf.iterable.to_iterator_object(vm);
+        vm.ctx
+            .new_tuple(vec![cls, vm.ctx.new_tuple(vec![iterator, counter])])
+    }
 }
 
 impl PyIter for PyEnumerate {
     fn next(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult {
-        let next_obj = iterator::call_next(vm, &zelf.iterator)?;
+        let next_obj = zelf.iterable.next(vm)?;
         let mut counter = zelf.counter.write();
         let position = counter.clone();
         *counter += 1;
@@ -14,7 +14,7 @@ use crate::common::lock::PyMutex;
 use crate::frame::Frame;
 use crate::function::{FuncArgs, OptionalArg};
 use crate::scope::Scope;
-use crate::slots::{Callable, Comparable, PyComparisonOp, SlotDescriptor, SlotGetattro};
+use crate::slots::{Callable, Comparable, Hashable, PyComparisonOp, SlotDescriptor, SlotGetattro};
 #[cfg(feature = "jit")]
 use crate::IntoPyObject;
 use crate::VirtualMachine;
@@ -431,7 +431,20 @@ impl SlotGetattro for PyBoundMethod {
     }
 }
 
-#[pyimpl(with(Callable, Comparable, SlotGetattro), flags(HAS_DICT))]
+impl Hashable for PyBoundMethod {
+    fn hash(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult<rustpython_common::hash::PyHash> {
+        // Must combine `function` and `object` the same way `Comparable::cmp`
+        // above compares them -- by identity -- or two bound methods that
+        // compare equal (e.g. two `x.__repr__` lookups) could hash unequally,
+        // breaking their use as dict keys or set members.
+        vm.state.hash_secret.hash_iter(
+            [&zelf.function, &zelf.object],
+            |obj| -> PyResult<rustpython_common::hash::PyHash> { Ok(obj.get_id() as _) },
+        )
+    }
+}
+
+#[pyimpl(with(Callable, Comparable, Hashable, SlotGetattro), flags(HAS_DICT))]
 impl PyBoundMethod {
     pub fn new(object: PyObjectRef, function: PyObjectRef) -> Self {
         PyBoundMethod { object, function }
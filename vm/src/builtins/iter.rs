@@ -4,7 +4,9 @@
 
 use crossbeam_utils::atomic::AtomicCell;
 
+use super::int;
 use super::pytype::PyTypeRef;
+use super::PyInt;
 use crate::slots::PyIter;
 use crate::vm::VirtualMachine;
 use crate::{
@@ -64,6 +66,50 @@ impl PySequenceIterator {
         };
         Ok(hint)
     }
+
+    #[pymethod(name = "__setstate__")]
+    fn setstate(&self, state: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        if let Some(i) = state.payload::<PyInt>() {
+            let position = if self.reversed {
+                std::cmp::max(int::try_to_primitive(i.as_bigint(), vm).unwrap_or(-1), -1)
+            } else {
+                let len = vm.obj_len(&self.obj)?;
+                std::cmp::min(
+                    int::try_to_primitive(i.as_bigint(), vm).unwrap_or(0),
+                    len as isize,
+                )
+            };
+            self.position.store(position);
+            Ok(())
+        } else {
+            Err(vm.new_type_error("an integer is required.".to_owned()))
+        }
+    }
+
+    #[pymethod(magic)]
+    fn reduce(&self, vm: &VirtualMachine) -> PyResult {
+        let builtin_name = if self.reversed { "reversed" } else { "iter" };
+        let builtin = vm.get_attribute(vm.builtins.clone(), builtin_name)?;
+        let pos = self.position.load();
+        let exhausted = if self.reversed {
+            pos < 0
+        } else {
+            vm.obj_len(&self.obj)
+                .map_or(false, |len| pos as usize >= len)
+        };
+        Ok(if exhausted {
+            vm.ctx.new_tuple(vec![
+                builtin,
+                vm.ctx.new_tuple(vec![vm.ctx.new_tuple(vec![])]),
+            ])
+        } else {
+            vm.ctx.new_tuple(vec![
+                builtin,
+                vm.ctx.new_tuple(vec![self.obj.clone()]),
+                vm.ctx.new_int(pos),
+            ])
+        })
+    }
 }
 
 impl PyIter for PySequenceIterator {
@@ -308,7 +308,7 @@ impl PyList {
                 return Ok(index);
             }
         }
-        Err(vm.new_value_error(format!("'{}' is not in list", vm.to_str(&needle)?)))
+        Err(vm.new_value_error(format!("{} is not in list", vm.to_repr(&needle)?)))
     }
 
     #[pymethod]
@@ -341,7 +341,7 @@ impl PyList {
             // defer delete out of borrow
             Ok(self.borrow_vec_mut().remove(index))
         } else {
-            Err(vm.new_value_error(format!("'{}' is not in list", vm.to_str(&needle)?)))
+            Err(vm.new_value_error("list.remove(x): x not in list".to_owned()))
         }
         .map(drop)
     }
@@ -30,6 +30,7 @@ mod decl {
     use crate::iterator;
     use crate::readline::{Readline, ReadlineResult};
     use crate::scope::Scope;
+    use crate::sequence;
     use crate::slots::PyComparisonOp;
     use crate::utils::Either;
     use crate::vm::VirtualMachine;
@@ -716,9 +717,15 @@ mod decl {
         if let Some(reversed_method) = vm.get_method(obj.clone(), "__reversed__") {
             vm.invoke(&reversed_method?, ())
         } else {
-            vm.get_method_or_type_error(obj.clone(), "__getitem__", || {
-                "argument to reversed() must be a sequence".to_owned()
-            })?;
+            // Mirrors CPython's own `reversed_new`, which falls back to the
+            // sequence protocol (`PySequence_Check`) rather than just probing
+            // for `__getitem__` directly, so a `dict` instance without
+            // `__reversed__` is still rejected even though it has `__getitem__`.
+            if !sequence::is_sequence(&obj, vm) {
+                return Err(
+                    vm.new_type_error("argument to reversed() must be a sequence".to_owned())
+                );
+            }
             let len = vm.obj_len(&obj)? as isize;
             let obj_iterator = PySequenceIterator::new_reversed(obj, len);
             Ok(obj_iterator.into_object(vm))
@@ -1,9 +1,13 @@
+// No `PyIterReturn`/`SlotIterator` here, see `crate::slots::PyIter`.
 use super::pytype::PyTypeRef;
+use crate::common::lock::PyRwLock;
 use crate::function::Args;
 use crate::iterator;
+use crate::pyobject::TypeProtocol;
 use crate::slots::PyIter;
 use crate::vm::VirtualMachine;
 use crate::{PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue};
+use crossbeam_utils::atomic::AtomicCell;
 
 /// map(func, *iterables) --> map object
 ///
@@ -13,7 +17,11 @@ use crate::{PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue};
 #[derive(Debug)]
 pub struct PyMap {
     mapper: PyObjectRef,
-    iterators: Vec<PyObjectRef>,
+    // Cleared as soon as the map is exhausted so the backing iterables
+    // (e.g. large generators) don't linger alive until the map itself is
+    // dropped.
+    iterators: PyRwLock<Vec<PyObjectRef>>,
+    exhausted: AtomicCell<bool>,
 }
 
 impl PyValue for PyMap {
@@ -37,31 +45,78 @@ impl PyMap {
             .collect::<Result<Vec<_>, _>>()?;
         PyMap {
             mapper: function,
-            iterators,
+            iterators: PyRwLock::new(iterators),
+            exhausted: AtomicCell::new(false),
         }
         .into_ref_with_type(vm, cls)
     }
 
     #[pymethod(name = "__length_hint__")]
     fn length_hint(&self, vm: &VirtualMachine) -> PyResult<usize> {
-        self.iterators.iter().try_fold(0, |prev, cur| {
+        self.iterators.read().iter().try_fold(0, |prev, cur| {
             let cur = iterator::length_hint(vm, cur.clone())?.unwrap_or(0);
             let max = std::cmp::max(prev, cur);
             Ok(max)
         })
     }
+
+    fn mark_exhausted(&self) {
+        self.exhausted.store(true);
+        self.iterators.write().clear();
+    }
+
+    // `map` has no extra state beyond its sub-iterators, which carry their
+    // own position, so only `__reduce__` is needed (no `__setstate__`).
+    #[pymethod(magic)]
+    fn reduce(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyObjectRef {
+        let cls = zelf.as_object().clone_class().into_object();
+        // `iterators` is cleared by `mark_exhausted` once the map has
+        // stopped, so an exhausted map reduces to `(cls, (mapper,))` with no
+        // sub-iterators; reconstructing with zero iterables and no stored
+        // state is exactly the empty/exhausted case the `is_empty` check
+        // below handles, so the round trip still ends in `StopIteration`.
+        let mut args = vec![zelf.mapper.clone()];
+        args.extend(zelf.iterators.read().iter().cloned());
+        vm.ctx.new_tuple(vec![cls, vm.ctx.new_tuple(args)])
+    }
 }
 
 impl PyIter for PyMap {
     fn next(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult {
-        let next_objs = zelf
-            .iterators
-            .iter()
-            .map(|iterator| iterator::call_next(vm, iterator))
-            .collect::<Result<Vec<_>, _>>()?;
+        if zelf.exhausted.load() {
+            return Err(vm.new_stop_iteration());
+        }
+        // Cloned out from under the lock (just bumping each `PyObjectRef`'s
+        // refcount) rather than held across `call_next` below: `call_next`
+        // runs arbitrary Python `__next__`, which can reenter `next()` on
+        // this same map (e.g. a sub-iterator whose `__next__` calls back
+        // into `next(m)`); holding the read guard across that call would
+        // deadlock against the reentrant call's own `mark_exhausted` trying
+        // to acquire the write lock.
+        let iterators = zelf.iterators.read().clone();
+        if iterators.is_empty() {
+            zelf.mark_exhausted();
+            return Err(vm.new_stop_iteration());
+        }
+        let mut next_objs = Vec::with_capacity(iterators.len());
+        for iterator in iterators.iter() {
+            match iterator::call_next(vm, iterator) {
+                Ok(obj) => next_objs.push(obj),
+                Err(err) => {
+                    zelf.mark_exhausted();
+                    return Err(err);
+                }
+            }
+        }
 
-        // the mapper itself can raise StopIteration which does stop the map iteration
-        vm.invoke(&zelf.mapper, next_objs)
+        // A `StopIteration` raised by the mapper itself propagates straight
+        // out of `next(m)`, same as CPython (e.g. `map(next, [iter([])])`).
+        vm.invoke(&zelf.mapper, next_objs).map_err(|err| {
+            if err.isinstance(&vm.ctx.exceptions.stop_iteration) {
+                zelf.mark_exhausted();
+            }
+            err
+        })
     }
 }
 
@@ -105,6 +105,10 @@ pub trait Buffer: Debug + PyThreadingConstraint {
     fn obj_bytes_mut(&self) -> BorrowedValueMut<[u8]>;
     fn release(&self);
 
+    fn is_readonly(&self) -> bool {
+        self.get_options().readonly
+    }
+
     fn as_contiguous(&self) -> Option<BorrowedValue<[u8]>> {
         if !self.get_options().contiguous {
             return None;
@@ -119,6 +123,12 @@ pub trait Buffer: Debug + PyThreadingConstraint {
         Some(self.obj_bytes_mut())
     }
 
+    /// A flat, contiguous copy of this buffer's bytes, gathering a strided
+    /// view's items in order even when [`as_contiguous()`] would return
+    /// `None`. Safe to call on a readonly buffer, since it only ever reads.
+    /// The default here is just `obj_bytes().to_vec()`, correct for any
+    /// buffer that's already contiguous; [`PyMemoryViewRef`]'s override
+    /// handles the non-contiguous case by walking item-by-item instead.
     fn to_contiguous(&self) -> Vec<u8> {
         self.obj_bytes().to_vec()
     }
@@ -148,6 +158,72 @@ impl BufferOptions {
         shape: Vec::new(),
         strides: Vec::new(),
     };
+
+    /// A read-only, C-contiguous view of `len` single-byte items, e.g. `bytes`.
+    pub fn simple_ro(len: usize) -> Self {
+        BufferOptions {
+            readonly: true,
+            len,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// A writable, C-contiguous view of `len` single-byte items, e.g. `bytearray`.
+    pub fn simple_rw(len: usize) -> Self {
+        BufferOptions {
+            readonly: false,
+            len,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// The size of this view in bytes (`len` is already the product of
+    /// `shape`, since this VM only supports `ndim == 1`; see the `TODO`
+    /// above), reflecting the view's own extent rather than the underlying
+    /// object's full size, e.g. after a slice or a `cast()`.
+    pub fn nbytes(&self) -> usize {
+        self.len * self.itemsize
+    }
+
+    /// Mirrors CPython's `PyBuffer_IsContiguous`: `order` is `'C'`
+    /// (row-major), `'F'` (column-major), or `'A'` (either). Not wired up to
+    /// `memoryview`'s own `contiguous` attributes, which track contiguity
+    /// separately as views are sliced.
+    pub fn is_contiguous(&self, order: char) -> bool {
+        // A 0- or 1-dimensional buffer has at most one axis to be contiguous
+        // along, so there's no distinction between row-major and
+        // column-major order to make.
+        if self.ndim <= 1 {
+            return true;
+        }
+        match order {
+            'C' => self.is_contiguous_order(true),
+            'F' => self.is_contiguous_order(false),
+            'A' => self.is_contiguous_order(true) || self.is_contiguous_order(false),
+            _ => false,
+        }
+    }
+
+    fn is_contiguous_order(&self, c_order: bool) -> bool {
+        let axes: Box<dyn Iterator<Item = usize>> = if c_order {
+            Box::new((0..self.ndim).rev())
+        } else {
+            Box::new(0..self.ndim)
+        };
+        let mut expected_stride = self.itemsize as isize;
+        for i in axes {
+            // An empty axis makes the whole buffer trivially contiguous:
+            // there's no data to be out of order.
+            if self.shape[i] == 0 {
+                return true;
+            }
+            if self.strides[i] != expected_stride {
+                return false;
+            }
+            expected_stride *= self.shape[i] as isize;
+        }
+        true
+    }
 }
 
 impl Default for BufferOptions {
@@ -284,8 +360,7 @@ impl PyMemoryView {
 
     #[pyproperty]
     fn nbytes(&self, vm: &VirtualMachine) -> PyResult<usize> {
-        self.try_not_released(vm)
-            .map(|_| self.options.len * self.options.itemsize)
+        self.try_not_released(vm).map(|_| self.options.nbytes())
     }
 
     #[pyproperty]
@@ -303,17 +378,18 @@ impl PyMemoryView {
         self.try_not_released(vm).map(|_| self.options.ndim)
     }
 
-    // TODO
+    // Views here are always 1-dimensional, so shape/strides derive directly
+    // from len/itemsize rather than `BufferOptions::shape`/`strides`.
     #[pyproperty]
     fn shape(&self, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
         self.try_not_released(vm)
             .map(|_| (self.options.len,).into_pyobject(vm))
     }
 
-    // TODO
     #[pyproperty]
     fn strides(&self, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
-        self.try_not_released(vm).map(|_| (0,).into_pyobject(vm))
+        self.try_not_released(vm)
+            .map(|_| (self.options.itemsize * self.step.abs() as usize,).into_pyobject(vm))
     }
 
     #[pyproperty]
@@ -610,7 +686,7 @@ impl PyMemoryView {
         vm: &VirtualMachine,
     ) -> PyResult<()> {
         zelf.try_not_released(vm)?;
-        if zelf.options.readonly {
+        if zelf.is_readonly() {
             return Err(vm.new_type_error("cannot modify read-only memory".to_owned()));
         }
         match needle {
@@ -723,7 +799,7 @@ impl PyMemoryView {
 
         let format_spec = Self::parse_format(format.as_str(), vm)?;
         let itemsize = format_spec.size();
-        let bytelen = zelf.options.len * zelf.options.itemsize;
+        let bytelen = zelf.options.nbytes();
 
         if bytelen % itemsize != 0 {
             return Err(
@@ -987,3 +1063,92 @@ pub fn unpack_bytes_seq_to_list(
 
     Ok(PyList::from(elements).into_ref(vm))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BufferOptions;
+
+    fn options(itemsize: usize, shape: Vec<usize>, strides: Vec<isize>) -> BufferOptions {
+        let ndim = shape.len();
+        BufferOptions {
+            itemsize,
+            ndim,
+            shape,
+            strides,
+            ..BufferOptions::DEFAULT
+        }
+    }
+
+    #[test]
+    fn is_contiguous_c_order() {
+        // a 3x4 array of 1-byte items laid out row-major: each row of 4 is
+        // contiguous, and rows themselves are placed back to back.
+        let buf = options(1, vec![3, 4], vec![4, 1]);
+        assert!(buf.is_contiguous('C'));
+        assert!(!buf.is_contiguous('F'));
+        assert!(buf.is_contiguous('A'));
+    }
+
+    #[test]
+    fn is_contiguous_f_order() {
+        // the same shape, but column-major: each column of 3 is contiguous.
+        let buf = options(1, vec![3, 4], vec![1, 3]);
+        assert!(!buf.is_contiguous('C'));
+        assert!(buf.is_contiguous('F'));
+        assert!(buf.is_contiguous('A'));
+    }
+
+    #[test]
+    fn is_contiguous_strided_is_neither() {
+        // a stride of 8 over 1-byte items (e.g. every other row skipped)
+        // isn't C- nor F-contiguous in either axis order.
+        let buf = options(1, vec![3, 4], vec![8, 1]);
+        assert!(!buf.is_contiguous('C'));
+        assert!(!buf.is_contiguous('F'));
+        assert!(!buf.is_contiguous('A'));
+    }
+
+    #[test]
+    fn is_contiguous_zero_and_low_dimensional_edge_cases() {
+        // 0- and 1-dimensional buffers are always contiguous in both orders,
+        // regardless of what their (unused) strides say.
+        let scalar = options(4, vec![], vec![]);
+        assert!(scalar.is_contiguous('C'));
+        assert!(scalar.is_contiguous('F'));
+
+        let vector = options(4, vec![10], vec![999]);
+        assert!(vector.is_contiguous('C'));
+        assert!(vector.is_contiguous('F'));
+
+        // a zero-length axis makes a higher-dimensional buffer trivially
+        // contiguous too, since there's no data to be out of order.
+        let empty_axis = options(1, vec![0, 4], vec![999, 1]);
+        assert!(empty_axis.is_contiguous('C'));
+        assert!(empty_axis.is_contiguous('F'));
+    }
+
+    #[test]
+    fn as_contiguous_detects_contiguity_via_try_buffer_from_object() {
+        // `bytearray` is always laid out contiguously, so `as_contiguous`
+        // should hand back the raw bytes directly; a strided `memoryview`
+        // slice (a step other than 1) isn't, so it should come back `None`
+        // -- exactly the check `bytes.join`/hashing/`socket.send` rely on to
+        // take the zero-copy fast path instead of gathering item-by-item.
+        crate::vm::Interpreter::default().enter(|vm| {
+            let bytearray = vm.ctx.new_bytearray(vec![1, 2, 3, 4]);
+            let buffer = super::try_buffer_from_object(vm, &bytearray).unwrap();
+            assert_eq!(buffer.as_contiguous().as_deref(), Some(&[1, 2, 3, 4][..]));
+            assert!(buffer.as_contiguous_mut().is_some());
+
+            let source = "memoryview(bytes(range(10)))[::2]";
+            let code_obj = vm
+                .compile(source, crate::compile::Mode::Eval, "<test>".to_owned())
+                .unwrap();
+            let scope = vm.new_scope_with_builtins();
+            let strided = vm.run_code_obj(code_obj, scope).unwrap();
+            let buffer = super::try_buffer_from_object(vm, &strided).unwrap();
+            assert!(buffer.as_contiguous().is_none());
+            assert!(buffer.as_contiguous_mut().is_none());
+        })
+    }
+}
@@ -24,7 +24,7 @@ use crate::utils::Either;
 use crate::VirtualMachine;
 use crate::{
     IdProtocol, IntoPyObject, ItemProtocol, PyClassDef, PyClassImpl, PyComparisonValue, PyContext,
-    PyIterable, PyObjectRef, PyRef, PyResult, PyValue, TryIntoRef, TypeProtocol,
+    PyObjectRef, PyRef, PyResult, PyValue, TryIntoRef, TypeProtocol,
 };
 use rustpython_common::atomic::{self, PyAtomic, Radium};
 use rustpython_common::hash;
@@ -755,9 +755,23 @@ impl PyStr {
     }
 
     #[pymethod]
-    fn join(&self, iterable: PyIterable<PyStrRef>, vm: &VirtualMachine) -> PyResult<String> {
-        let iter = iterable.iter(vm)?;
-        self.value.py_join(iter)
+    fn join(&self, iterable: PyObjectRef, vm: &VirtualMachine) -> PyResult<String> {
+        // `vm.extract_elements` already takes the known-length fast path for
+        // `list`/`tuple` (indexing their backing storage directly instead of
+        // going through `__iter__`/`__next__`), falling back to the generic
+        // iterator protocol for everything else, so there's no separate
+        // sequence-protocol wrapper to add here.
+        let elements: Vec<PyStrRef> = vm.extract_elements(&iterable)?;
+        let mut iter = elements.into_iter();
+        let mut joined = match iter.next() {
+            Some(elem) => elem.as_str().to_owned(),
+            None => return Ok(String::new()),
+        };
+        for elem in iter {
+            joined.push_str(self.as_str());
+            joined.push_str(elem.as_str());
+        }
+        Ok(joined)
     }
 
     #[inline]
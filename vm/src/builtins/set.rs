@@ -815,6 +815,58 @@ impl PyIter for PySetIterator {
     }
 }
 
+// Backs the set-algebra operators (`&`, `|`, `^`, `-`) on `dict_keys`/
+// `dict_items`: unlike `PySet`'s own operators (which require an actual
+// set/frozenset operand via `SetIterable`), CPython's dict views accept any
+// iterable on either side, so these take a plain `PyIterable` and always
+// build a fresh, unordered `set` -- never a view or a (potentially ordered)
+// subclass -- matching `dictviews_and`/`_or`/`_xor`/`_sub` in `Objects/dictobject.c`.
+pub(crate) fn dict_view_and(
+    view: PyIterable,
+    other: PyIterable,
+    vm: &VirtualMachine,
+) -> PyResult<PySet> {
+    let lhs = PySetInner::new(view, vm)?;
+    Ok(PySet {
+        inner: lhs.intersection(other, vm)?,
+    })
+}
+
+pub(crate) fn dict_view_or(
+    view: PyIterable,
+    other: PyIterable,
+    vm: &VirtualMachine,
+) -> PyResult<PySet> {
+    let lhs = PySetInner::new(view, vm)?;
+    Ok(PySet {
+        inner: lhs.union(other, vm)?,
+    })
+}
+
+pub(crate) fn dict_view_xor(
+    view: PyIterable,
+    other: PyIterable,
+    vm: &VirtualMachine,
+) -> PyResult<PySet> {
+    let lhs = PySetInner::new(view, vm)?;
+    Ok(PySet {
+        inner: lhs.symmetric_difference(other, vm)?,
+    })
+}
+
+// `view - other`; a reflected `other - view` is just `dict_view_sub(other,
+// view, vm)` since both operands are plain `PyIterable`s here.
+pub(crate) fn dict_view_sub(
+    view: PyIterable,
+    other: PyIterable,
+    vm: &VirtualMachine,
+) -> PyResult<PySet> {
+    let lhs = PySetInner::new(view, vm)?;
+    Ok(PySet {
+        inner: lhs.difference(other, vm)?,
+    })
+}
+
 pub fn init(context: &PyContext) {
     PySet::extend_class(context, &context.types.set_type);
     PyFrozenSet::extend_class(context, &context.types.frozenset_type);
@@ -1,9 +1,13 @@
+use super::pybool::{boolval, IntoPyBool};
 use super::pytype::PyTypeRef;
+use crate::exceptions::PyBaseExceptionRef;
 use crate::function::Args;
 use crate::iterator;
+use crate::pyobject::TypeProtocol;
 use crate::slots::PyIter;
 use crate::vm::VirtualMachine;
 use crate::{PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue};
+use crossbeam_utils::atomic::AtomicCell;
 
 pub type PyZipRef = PyRef<PyZip>;
 
@@ -11,6 +15,8 @@ pub type PyZipRef = PyRef<PyZip>;
 #[derive(Debug)]
 pub struct PyZip {
     iterators: Vec<PyObjectRef>,
+    strict: AtomicCell<bool>,
+    exhausted: AtomicCell<bool>,
 }
 
 impl PyValue for PyZip {
@@ -19,34 +25,148 @@ impl PyValue for PyZip {
     }
 }
 
+// `strict` goes through `IntoPyBool`, the same truthiness-coercion type
+// every other boolean keyword arg in this VM uses (e.g. `PrintOptions.flush`
+// in `builtins/make_module.rs`): it evaluates the argument's truthiness rather
+// than requiring an actual `bool`, so `zip(a, b, strict=1)` already behaves
+// like `strict=True` instead of raising `TypeError`, consistent with every
+// other such flag. `__setstate__` (below) applies the same coercion via
+// `boolval`, matching real CPython's `zip.__setstate__`, which also accepts
+// any truthy/falsy value rather than validating it's a `bool`.
+#[derive(FromArgs)]
+pub struct ZipArgs {
+    #[pyarg(named, default = "IntoPyBool::FALSE")]
+    strict: IntoPyBool,
+}
+
 #[pyimpl(with(PyIter), flags(BASETYPE))]
 impl PyZip {
     #[pyslot]
-    fn tp_new(cls: PyTypeRef, iterables: Args, vm: &VirtualMachine) -> PyResult<PyZipRef> {
+    fn tp_new(
+        cls: PyTypeRef,
+        iterables: Args,
+        args: ZipArgs,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyZipRef> {
         let iterators = iterables
             .into_iter()
             .map(|iterable| iterator::get_iter(vm, iterable))
             .collect::<Result<Vec<_>, _>>()?;
-        PyZip { iterators }.into_ref_with_type(vm, cls)
+        PyZip {
+            iterators,
+            strict: AtomicCell::new(args.strict.to_bool()),
+            exhausted: AtomicCell::new(false),
+        }
+        .into_ref_with_type(vm, cls)
+    }
+
+    #[pymethod(magic)]
+    fn reduce(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyObjectRef {
+        let cls = zelf.as_object().clone_class().into_object();
+        let iterators = vm.ctx.new_tuple(zelf.iterators.clone());
+        if zelf.strict.load() {
+            vm.ctx
+                .new_tuple(vec![cls, iterators, vm.ctx.new_bool(true)])
+        } else {
+            vm.ctx.new_tuple(vec![cls, iterators])
+        }
+    }
+
+    #[pymethod(magic)]
+    fn setstate(&self, state: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        self.strict.store(boolval(vm, state)?);
+        Ok(())
     }
 }
 
 impl PyIter for PyZip {
     fn next(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult {
-        if zelf.iterators.is_empty() {
+        if zelf.iterators.is_empty() || zelf.exhausted.load() {
+            zelf.exhausted.store(true);
             Err(vm.new_stop_iteration())
+        } else if let [iterator] = zelf.iterators.as_slice() {
+            // `zip(single_iterable)` is common enough (and `strict` can never
+            // trigger with only one operand) to skip the general `Vec`
+            // bookkeeping below and build the 1-tuple directly.
+            match iterator::call_next(vm, iterator) {
+                Ok(obj) => Ok(vm.ctx.new_tuple(vec![obj])),
+                Err(err) => {
+                    zelf.exhausted.store(true);
+                    Err(err)
+                }
+            }
         } else {
-            let next_objs = zelf
-                .iterators
-                .iter()
-                .map(|iterator| iterator::call_next(vm, iterator))
-                .collect::<Result<Vec<_>, _>>()?;
+            // `next_objs` is already sized exactly to `iterators.len()`, and
+            // `vm.ctx.new_tuple` hands it straight to `Vec::into_boxed_slice`
+            // (see `PyTuple::with_elements`), which is a no-op reallocation
+            // since capacity equals length. There's no buffer left over to
+            // pool afterwards: the tuple owns that same allocation for as
+            // long as it's alive, so a `PyMutex`-held scratch buffer would
+            // just be an extra allocation that's never free to reuse.
+            let mut next_objs = Vec::with_capacity(zelf.iterators.len());
+            for (i, iterator) in zelf.iterators.iter().enumerate() {
+                match iterator::call_next(vm, iterator) {
+                    Ok(obj) => next_objs.push(obj),
+                    Err(err) => {
+                        zelf.exhausted.store(true);
+                        if zelf.strict.load() && err.isinstance(&vm.ctx.exceptions.stop_iteration) {
+                            return Err(zelf.strict_stop_error(i, vm));
+                        }
+                        return Err(err);
+                    }
+                }
+            }
 
             Ok(vm.ctx.new_tuple(next_objs))
         }
     }
 }
 
+impl PyZip {
+    /// Build the `ValueError` for `zip(..., strict=True)` once any iterator
+    /// stops: if the first iterator stopped (`i == 0`), the remaining ones
+    /// must be checked too, since one of *them* running longer is the
+    /// interesting case; if a later iterator stopped (`i > 0`), the earlier
+    /// ones already produced a value this round so they must be the longer
+    /// ones.
+    fn strict_stop_error(&self, i: usize, vm: &VirtualMachine) -> PyBaseExceptionRef {
+        if i > 0 {
+            return vm.new_value_error(format!(
+                "zip() argument {} is shorter than {}",
+                i + 1,
+                Self::argument_range(i)
+            ));
+        }
+        // `enumerate()` runs over the full `iterators` slice before `.skip(1)`
+        // drops the first element, so `j` is already each iterator's original
+        // position (1, 2, ...), not reset to 0 by the skip.
+        for (j, iterator) in self.iterators.iter().enumerate().skip(1) {
+            match iterator::call_next(vm, iterator) {
+                Ok(_) => {
+                    return vm.new_value_error(format!(
+                        "zip() argument {} is longer than {}",
+                        j + 1,
+                        Self::argument_range(j)
+                    ));
+                }
+                Err(err) if err.isinstance(&vm.ctx.exceptions.stop_iteration) => continue,
+                Err(err) => return err,
+            }
+        }
+        vm.new_stop_iteration()
+    }
+
+    /// Render "argument 1" for a single preceding argument or "arguments
+    /// 1-n" for several, matching CPython's `zip(strict=True)` wording.
+    fn argument_range(n: usize) -> String {
+        if n == 1 {
+            "argument 1".to_owned()
+        } else {
+            format!("arguments 1-{}", n)
+        }
+    }
+}
+
 pub fn init(context: &PyContext) {
     PyZip::extend_class(context, &context.types.zip_type);
 }
@@ -1,6 +1,7 @@
 use crate::builtins::memory::{try_buffer_from_object, BufferRef};
 use crate::builtins::PyStrRef;
 use crate::common::borrow::{BorrowedValue, BorrowedValueMut};
+use crate::exceptions::PyBaseExceptionRef;
 use crate::vm::VirtualMachine;
 use crate::{PyObjectRef, PyResult, TryFromObject};
 
@@ -54,7 +55,7 @@ impl PyBytesLike {
         if buffer.get_options().contiguous {
             Ok(Self(buffer))
         } else {
-            Err(vm.new_type_error("non-contiguous buffer is not a bytes-like object".to_owned()))
+            Err(non_contiguous_error(vm))
         }
     }
 
@@ -79,9 +80,10 @@ pub fn try_bytes_like<R>(
     f: impl FnOnce(&[u8]) -> R,
 ) -> PyResult<R> {
     let buffer = try_buffer_from_object(vm, obj)?;
-    buffer.as_contiguous().map(|x| f(&*x)).ok_or_else(|| {
-        vm.new_type_error("non-contiguous buffer is not a bytes-like object".to_owned())
-    })
+    buffer
+        .as_contiguous()
+        .map(|x| f(&*x))
+        .ok_or_else(|| non_contiguous_error(vm))
 }
 
 pub fn try_rw_bytes_like<R>(
@@ -93,16 +95,25 @@ pub fn try_rw_bytes_like<R>(
     buffer
         .as_contiguous_mut()
         .map(|mut x| f(&mut *x))
-        .ok_or_else(|| vm.new_type_error("buffer is not a read-write bytes-like object".to_owned()))
+        .ok_or_else(|| non_contiguous_error(vm))
+}
+
+// Matches real CPython's `memoryview`/buffer-protocol consumers (hashlib,
+// zlib, binascii, ...), which all reject a non-contiguous buffer with
+// `BufferError: memoryview: underlying buffer is not C-contiguous` rather
+// than copying it into a temporary — there's no such "lend a contiguous
+// copy" fallback on the real `Py_buffer`/`PyObject_GetBuffer` path either,
+// so none is added here.
+fn non_contiguous_error(vm: &VirtualMachine) -> PyBaseExceptionRef {
+    vm.new_buffer_error("memoryview: underlying buffer is not C-contiguous".to_owned())
 }
 
 impl PyRwBytesLike {
     pub fn new(vm: &VirtualMachine, obj: &PyObjectRef) -> PyResult<Self> {
         let buffer = try_buffer_from_object(vm, obj)?;
-        let options = buffer.get_options();
-        if !options.contiguous {
-            Err(vm.new_type_error("non-contiguous buffer is not a bytes-like object".to_owned()))
-        } else if options.readonly {
+        if !buffer.get_options().contiguous {
+            Err(non_contiguous_error(vm))
+        } else if buffer.is_readonly() {
             Err(vm.new_type_error("buffer is not a read-write bytes-like object".to_owned()))
         } else {
             Ok(Self(buffer))
@@ -1447,11 +1447,7 @@ impl ExecutingFrame<'_> {
     fn _send(&self, coro: &PyObjectRef, val: PyObjectRef, vm: &VirtualMachine) -> PyResult {
         match self.builtin_coro(coro) {
             Some(coro) => coro.send(val, vm),
-            None if vm.is_none(&val) => iterator::call_next(vm, coro),
-            None => {
-                let meth = vm.get_attribute(coro.clone(), "send")?;
-                vm.invoke(&meth, (val,))
-            }
+            None => iterator::call_send(vm, coro, val),
         }
     }
 
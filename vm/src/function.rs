@@ -13,6 +13,9 @@ use result_like::impl_option_like;
 use std::marker::PhantomData;
 use std::ops::RangeInclusive;
 
+// No vectorcall slot here; every callable goes through `tp_call` with a
+// `FuncArgs` (see `VirtualMachine::_invoke`). The tuple `IntoFuncArgs` impls
+// below already avoid the positional-only-call allocation vectorcall would.
 pub trait IntoFuncArgs: Sized {
     fn into_args(self, vm: &VirtualMachine) -> FuncArgs;
     fn into_method_args(self, obj: PyObjectRef, vm: &VirtualMachine) -> FuncArgs {
@@ -42,6 +42,20 @@ pub fn get_iter(vm: &VirtualMachine, iter_target: PyObjectRef) -> PyResult {
     }
 }
 
+/// Drive `iter_obj` the way `yield from` drives its subiterator: call its
+/// `send` method if it has one, otherwise fall back to `next(iter_obj)` when
+/// `value` is `None` (so plain iterators, which only implement `__next__`,
+/// can still be delegated to). Sending a non-`None` value into an iterator
+/// without `send` raises `AttributeError`, matching CPython.
+pub fn call_send(vm: &VirtualMachine, iter_obj: &PyObjectRef, value: PyObjectRef) -> PyResult {
+    if vm.is_none(&value) {
+        call_next(vm, iter_obj)
+    } else {
+        let meth = vm.get_attribute(iter_obj.clone(), "send")?;
+        vm.invoke(&meth, (value,))
+    }
+}
+
 pub fn call_next(vm: &VirtualMachine, iter_obj: &PyObjectRef) -> PyResult {
     let iternext = {
         let cls = iter_obj.class();
@@ -51,6 +65,10 @@ pub fn call_next(vm: &VirtualMachine, iter_obj: &PyObjectRef) -> PyResult {
     iternext(iter_obj, vm)
 }
 
+// `call_next`'s `PyResult` already carries a raised `StopIteration`'s value
+// through `Err` untouched, so there's no separate "into_result" conversion
+// needed; `get_next_object` and `stop_iter_with_value` below cover the
+// two shapes call sites actually need.
 /*
  * Helper function to retrieve the next object (or none) from an iterator.
  */
@@ -155,3 +173,75 @@ pub fn length_hint(vm: &VirtualMachine, iter: PyObjectRef) -> PyResult<Option<us
 // pub fn seq_iter_method(obj: PyObjectRef) -> PySequenceIterator {
 //     PySequenceIterator::new_forward(obj)
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pyobject::ItemProtocol;
+    use crate::vm::Interpreter;
+
+    #[test]
+    fn test_get_next_object_converts_both_ways() {
+        // `get_next_object` is this VM's `PyResult -> PyResult<Option<_>>`
+        // convenience: a yielded value comes back `Some`, and exhaustion
+        // (a raised `StopIteration`) comes back `None` instead of an `Err`.
+        Interpreter::default().enter(|vm| {
+            let list = vm.ctx.new_list(vec![vm.ctx.new_int(1)]);
+            let iter = get_iter(vm, list).unwrap();
+
+            let first = get_next_object(vm, &iter).unwrap();
+            assert!(matches!(first, Some(ref obj) if int::get_value(obj).clone() == 1.into()));
+
+            let exhausted = get_next_object(vm, &iter).unwrap();
+            assert!(exhausted.is_none());
+        })
+    }
+
+    #[test]
+    fn test_stop_iter_with_value_round_trips_through_stop_iter_value() {
+        // The inverse direction: wrapping a value up as the `StopIteration`
+        // exception it'll be raised as, then unwrapping it back out, must
+        // preserve the value rather than losing it to the bare exception.
+        Interpreter::default().enter(|vm| {
+            let value = vm.ctx.new_str("generator result".to_owned());
+            let exc = stop_iter_with_value(value.clone(), vm);
+            assert!(exc.isinstance(&vm.ctx.exceptions.stop_iteration));
+
+            let unwrapped = stop_iter_value(vm, &exc);
+            assert!(unwrapped.is(&value));
+        })
+    }
+
+    #[test]
+    fn test_call_next_preserves_stop_iteration_value_from_python() {
+        // The value-carrying direction, but starting from a real Python
+        // `__next__` that raises `StopIteration(42)` itself (as a generator
+        // does on `return 42`), confirming the attached value survives
+        // coming back out through `call_next`'s plain `PyResult`, not just
+        // when built directly in Rust via `stop_iter_with_value`.
+        Interpreter::default().enter(|vm| {
+            let source = r#"
+def gen():
+    yield 1
+    return 42
+"#;
+            let code_obj = vm
+                .compile(source, crate::compile::Mode::Exec, "<test>".to_owned())
+                .unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code_obj, scope.clone()).unwrap();
+            let gen = scope.locals.as_object().get_item("gen", vm).unwrap();
+            let gen = vm.invoke(&gen, ()).unwrap();
+
+            let first = call_next(vm, &gen).unwrap();
+            assert_eq!(int::get_value(&first).clone(), 1.into());
+
+            let err = call_next(vm, &gen).unwrap_err();
+            assert!(err.isinstance(&vm.ctx.exceptions.stop_iteration));
+            assert_eq!(
+                int::get_value(&stop_iter_value(vm, &err)).clone(),
+                42.into()
+            );
+        })
+    }
+}
@@ -646,6 +646,20 @@ where
     fn get_item(&self, key: T, vm: &VirtualMachine) -> PyResult;
     fn set_item(&self, key: T, value: PyObjectRef, vm: &VirtualMachine) -> PyResult<()>;
     fn del_item(&self, key: T, vm: &VirtualMachine) -> PyResult<()>;
+
+    /// Like the C-API `PyMapping_HasKey`: look up `key` via the subscript
+    /// protocol, treating a raised `KeyError` as absence while propagating
+    /// any other exception.
+    fn contains(&self, key: T, vm: &VirtualMachine) -> PyResult<bool>
+    where
+        T: Sized,
+    {
+        match self.get_item(key, vm) {
+            Ok(_) => Ok(true),
+            Err(exc) if exc.isinstance(&vm.ctx.exceptions.key_error) => Ok(false),
+            Err(exc) => Err(exc),
+        }
+    }
 }
 
 impl<T> ItemProtocol<T> for PyObjectRef
@@ -744,6 +758,14 @@ where
     }
 }
 
+/// Already holds the resolved iterator object (the result of `__iter__`),
+/// not the original iterable, so advancing it never re-resolves `__iter__`.
+/// Each step calls `__next__` through the type's `iternext` slot (a plain
+/// function pointer looked up once per MRO walk) rather than a generic
+/// attribute lookup, so there's no separate "iterator that wraps an
+/// already-resolved iterator" type to add here for per-element overhead.
+/// (This VM also has no `odict.rs` / `protocol/iter.rs` split — `OrderedDict`
+/// and its views live in `Lib/collections/__init__.py` as plain Python.)
 pub struct PyIterator<'a, T> {
     vm: &'a VirtualMachine,
     obj: PyObjectRef,
@@ -1190,6 +1212,15 @@ impl<T> PySequence<T> {
     pub fn as_slice(&self) -> &[T] {
         &self.0
     }
+
+    /// Mirrors CPython's `PySequence_GetItem`: a negative `i` is normalized
+    /// by adding `len()` before indexing, and an `IndexError` is raised when
+    /// `i` is still out of range afterwards.
+    pub fn get_item(&self, i: isize, vm: &VirtualMachine) -> PyResult<&T> {
+        crate::sliceable::wrap_index(i, self.0.len())
+            .map(|i| &self.0[i])
+            .ok_or_else(|| vm.new_index_error("index out of range".to_owned()))
+    }
 }
 impl<T: TryFromObject> TryFromObject for PySequence<T> {
     fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
@@ -1322,3 +1353,109 @@ impl PyMethod {
         vm.invoke(func, args)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ItemProtocol, PyIterable, PySequence, TryFromObject, TypeProtocol};
+    use crate::builtins::int;
+    use crate::compile;
+    use crate::vm::Interpreter;
+
+    #[test]
+    fn contains_treats_key_error_as_absent() {
+        Interpreter::default().enter(|vm| {
+            let dict = vm.ctx.new_dict();
+            dict.set_item("present", vm.ctx.new_int(1), vm).unwrap();
+            assert!(dict.contains("present", vm).unwrap());
+            assert!(!dict.contains("missing", vm).unwrap());
+        })
+    }
+
+    #[test]
+    fn contains_propagates_other_errors() {
+        Interpreter::default().enter(|vm| {
+            // a key whose `__eq__`/`__hash__` raises should surface the
+            // error rather than being swallowed as a miss.
+            let unhashable = vm.ctx.new_list(vec![]);
+            let dict = vm.ctx.new_dict();
+            let err = dict.as_object().contains(unhashable, vm).unwrap_err();
+            assert!(err.isinstance(&vm.ctx.exceptions.type_error));
+        })
+    }
+
+    #[test]
+    fn item_protocol_get_set_del_on_dict() {
+        Interpreter::default().enter(|vm| {
+            let dict = vm.ctx.new_dict().into_object();
+            dict.set_item("key", vm.ctx.new_int(1), vm).unwrap();
+            assert_eq!(int::get_value(&dict.get_item("key", vm).unwrap()), &1.into());
+            dict.del_item("key", vm).unwrap();
+            assert!(dict.get_item("key", vm).is_err());
+        })
+    }
+
+    #[test]
+    fn item_protocol_works_against_any_object_with_dunder_methods() {
+        // ItemProtocol dispatches through `__getitem__`/`__setitem__`/
+        // `__delitem__`, so it works uniformly against any object that
+        // defines them, not just `dict` -- here a plain `list`.
+        Interpreter::default().enter(|vm| {
+            let list = vm
+                .ctx
+                .new_list(vec![vm.ctx.new_int(1), vm.ctx.new_int(2)]);
+            assert_eq!(int::get_value(&list.get_item(0, vm).unwrap()), &1.into());
+            list.set_item(0, vm.ctx.new_int(9), vm).unwrap();
+            assert_eq!(int::get_value(&list.get_item(0, vm).unwrap()), &9.into());
+            list.del_item(0, vm).unwrap();
+            assert_eq!(int::get_value(&list.get_item(0, vm).unwrap()), &2.into());
+        })
+    }
+
+    #[test]
+    fn py_sequence_get_item_normalizes_negative_index() {
+        // A custom old-style sequence (only `__getitem__`, no `__iter__`) is
+        // still materialized into a `PySequence` via the old-style iteration
+        // fallback, and `get_item` normalizes a negative index the same way
+        // CPython's `PySequence_GetItem` does for `sq_item`.
+        Interpreter::default().enter(|vm| {
+            let source = "
+class Custom:
+    def __getitem__(self, i):
+        if i >= 3:
+            raise IndexError(i)
+        return i * i
+
+c = Custom()
+";
+            let scope = vm.new_scope_with_builtins();
+            let code = vm
+                .compile(source, compile::Mode::Exec, "<test>".to_owned())
+                .unwrap();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let custom = scope.locals.as_object().get_item("c", vm).unwrap();
+
+            let seq: PySequence = TryFromObject::try_from_object(vm, custom).unwrap();
+            assert_eq!(seq.as_slice().len(), 3);
+            assert_eq!(int::get_value(seq.get_item(-1, vm).unwrap()), &4.into());
+            assert_eq!(int::get_value(seq.get_item(0, vm).unwrap()), &0.into());
+            assert_eq!(int::get_value(seq.get_item(-3, vm).unwrap()), &0.into());
+
+            let err = seq.get_item(-4, vm).unwrap_err();
+            assert!(err.isinstance(&vm.ctx.exceptions.index_error));
+            let err = seq.get_item(3, vm).unwrap_err();
+            assert!(err.isinstance(&vm.ctx.exceptions.index_error));
+        })
+    }
+
+    #[test]
+    fn py_iterator_reports_length_hint_as_size_hint() {
+        Interpreter::default().enter(|vm| {
+            let list = vm
+                .ctx
+                .new_list(vec![vm.ctx.new_int(1), vm.ctx.new_int(2), vm.ctx.new_int(3)]);
+            let iterable: PyIterable = TryFromObject::try_from_object(vm, list).unwrap();
+            let iter = iterable.iter(vm).unwrap();
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+        })
+    }
+}
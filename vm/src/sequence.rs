@@ -1,8 +1,18 @@
+use crate::pyobject::TypeProtocol;
 use crate::slots::PyComparisonOp;
 use crate::vm::VirtualMachine;
 use crate::{PyObjectRef, PyResult};
 use num_traits::cast::ToPrimitive;
 
+// No `PySequenceMethods` slot struct here; sequence dunders are plain
+// `#[pymethod]`s on each type (see `builtins/list.rs`, `sliceable.rs`).
+
+/// Mirrors CPython's `PySequence_Check`: no `sq_item` slot to probe here, so
+/// this checks the closest equivalent, `__getitem__`, excluding `dict`.
+pub(crate) fn is_sequence(obj: &PyObjectRef, vm: &VirtualMachine) -> bool {
+    !obj.isinstance(&vm.ctx.types.dict_type) && obj.class().has_attr("__getitem__")
+}
+
 pub(super) type DynPyIter<'a> = Box<dyn ExactSizeIterator<Item = &'a PyObjectRef> + 'a>;
 
 #[allow(clippy::len_without_is_empty)]
@@ -106,3 +116,25 @@ pub(crate) fn seq_mul(seq: &impl SimpleSeq, repetitions: isize) -> SeqMul {
         iter: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::is_sequence;
+    use crate::Interpreter;
+
+    #[test]
+    fn test_is_sequence() {
+        Interpreter::default().enter(|vm| {
+            let cases: &[(&str, crate::PyObjectRef, bool)] = &[
+                ("str", vm.ctx.new_str("abc"), true),
+                ("bytes", vm.ctx.new_bytes(b"abc".to_vec()), true),
+                ("list", vm.ctx.new_list(vec![]), true),
+                ("tuple", vm.ctx.new_tuple(vec![]), true),
+                ("dict", vm.ctx.new_dict().into(), false),
+            ];
+            for (name, obj, expected) in cases {
+                assert_eq!(is_sequence(obj, vm), *expected, "{}", name);
+            }
+        })
+    }
+}
@@ -390,6 +390,12 @@ impl TryFromObject for SequenceIndex {
 // }
 
 // Use PySliceableSequence::wrap_index for implementors
+//
+// This is the single shared negative-index normalization helper every
+// sequence `__getitem__`/`__setitem__`/`__delitem__` in this tree goes
+// through (via `PySliceableSequence::wrap_index` above); there's no separate
+// copy of this logic in `sequence.rs` to consolidate, since `sequence.rs`
+// doesn't implement per-type item access at all (see its module comment).
 pub(crate) fn wrap_index(p: isize, len: usize) -> Option<usize> {
     let neg = p.is_negative();
     let p = p.wrapping_abs() as usize;
@@ -497,3 +503,23 @@ pub(crate) fn convert_slice(
 
     Ok((range, step, is_negative_step))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_index;
+
+    #[test]
+    fn test_wrap_index() {
+        // in range, from the front and from the back.
+        assert_eq!(wrap_index(0, 5), Some(0));
+        assert_eq!(wrap_index(4, 5), Some(4));
+        assert_eq!(wrap_index(-1, 5), Some(4));
+        assert_eq!(wrap_index(-5, 5), Some(0));
+        // one past the end on either side.
+        assert_eq!(wrap_index(5, 5), None);
+        assert_eq!(wrap_index(-6, 5), None);
+        // an empty sequence has no valid index at all.
+        assert_eq!(wrap_index(0, 0), None);
+        assert_eq!(wrap_index(-1, 0), None);
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{PyObjectRef, VirtualMachine};
+pub(crate) use decl::{
+    context_var_get, missing, restore_vars, snapshot_vars, ContextVarsSnapshot,
+    PyContextTokenMissing, PyContextVarRef,
+};
+
+pub(crate) fn make_module(vm: &VirtualMachine) -> PyObjectRef {
+    let module = decl::make_module(vm);
+    // `Token.MISSING` is a singleton sentinel, not a regular class attribute,
+    // so it's wired up here rather than via `#[pyattr]`.
+    let token_type = vm.get_attribute(module.clone(), "Token").unwrap();
+    vm.set_attr(&token_type, "MISSING", missing(vm)).unwrap();
+    module
+}
+
+#[pymodule]
+mod decl {
+    use crate::builtins::pystr::PyStrRef;
+    use crate::builtins::pytype::PyTypeRef;
+    use crate::common::lock::PyRwLock;
+    use crate::function::{FuncArgs, OptionalArg};
+    use crate::vm::VirtualMachine;
+    use crate::{IdProtocol, PyObjectRef, PyRef, PyResult, PyValue, StaticType};
+    use crossbeam_utils::atomic::AtomicCell;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread_local;
+
+    /// Bumped once per `ContextVar()` construction to hand out a unique id,
+    /// used as each `PyContext`'s `HashMap` key for that variable (a
+    /// `ContextVar` has no other stable, hashable identity to key on).
+    static NEXT_VAR_ID: AtomicUsize = AtomicUsize::new(0);
+
+    fn next_var_id() -> usize {
+        NEXT_VAR_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    #[pyattr]
+    #[pyclass(module = "contextvars", name = "Context")]
+    #[derive(Debug, Default)]
+    pub struct PyContext {
+        vars: PyRwLock<HashMap<usize, (PyContextVarRef, PyObjectRef)>>,
+        entered: AtomicCell<bool>,
+    }
+
+    pub type PyContextRef = PyRef<PyContext>;
+
+    impl PyValue for PyContext {
+        fn class(_vm: &VirtualMachine) -> &PyTypeRef {
+            Self::static_type()
+        }
+    }
+
+    #[pyimpl]
+    impl PyContext {
+        #[pyslot]
+        fn tp_new(cls: PyTypeRef, vm: &VirtualMachine) -> PyResult<PyContextRef> {
+            PyContext::default().into_ref_with_type(vm, cls)
+        }
+
+        /// Run `callable` with this context activated, restoring the previous
+        /// context on return (or on unwind). Each `Context` may only be
+        /// entered once at a time; re-entering while already active raises.
+        #[pymethod]
+        fn run(
+            zelf: PyContextRef,
+            callable: PyObjectRef,
+            args: FuncArgs,
+            vm: &VirtualMachine,
+        ) -> PyResult {
+            if zelf.entered.compare_exchange(false, true).is_err() {
+                return Err(vm.new_runtime_error(format!(
+                    "cannot enter context: {} is already entered",
+                    vm.to_repr(zelf.as_object())?.as_str()
+                )));
+            }
+            CONTEXT_STACK.with(|stack| stack.write().push(zelf.clone()));
+            let result = vm.invoke(&callable, args);
+            let popped = CONTEXT_STACK.with(|stack| stack.write().pop());
+            debug_assert!(popped.map_or(false, |ctx| ctx.is(&zelf)));
+            zelf.entered.store(false);
+            result
+        }
+    }
+
+    #[pyattr]
+    #[pyclass(module = "contextvars", name = "ContextVar")]
+    #[derive(Debug)]
+    pub struct PyContextVar {
+        id: usize,
+        name: PyStrRef,
+        default: Option<PyObjectRef>,
+    }
+
+    pub type PyContextVarRef = PyRef<PyContextVar>;
+
+    impl PyValue for PyContextVar {
+        fn class(_vm: &VirtualMachine) -> &PyTypeRef {
+            Self::static_type()
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct ContextVarNewArgs {
+        #[pyarg(positional)]
+        name: PyStrRef,
+        #[pyarg(named, optional)]
+        default: OptionalArg<PyObjectRef>,
+    }
+
+    #[pyimpl]
+    impl PyContextVar {
+        #[pyslot]
+        fn tp_new(
+            cls: PyTypeRef,
+            args: ContextVarNewArgs,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyContextVarRef> {
+            PyContextVar {
+                id: next_var_id(),
+                name: args.name,
+                default: args.default.into_option(),
+            }
+            .into_ref_with_type(vm, cls)
+        }
+
+        #[pyproperty]
+        fn name(&self) -> PyStrRef {
+            self.name.clone()
+        }
+
+        #[pymethod]
+        fn get(
+            zelf: PyRef<Self>,
+            default: OptionalArg<PyObjectRef>,
+            vm: &VirtualMachine,
+        ) -> PyResult {
+            context_var_get(&zelf, default.into_option(), vm)?.ok_or_else(|| {
+                vm.new_lookup_error(format!("<ContextVar name={:?}>", zelf.name.as_str()))
+            })
+        }
+
+        #[pymethod]
+        fn set(zelf: PyRef<Self>, value: PyObjectRef, vm: &VirtualMachine) -> PyContextTokenRef {
+            let ctx = current_context(vm);
+            let old_value = ctx
+                .vars
+                .write()
+                .insert(zelf.id, (zelf.clone(), value))
+                .map(|(_, v)| v);
+            PyContextToken {
+                var: zelf,
+                old_value,
+                context: ctx,
+                used: AtomicCell::new(false),
+            }
+            .into_ref(vm)
+        }
+
+        #[pymethod]
+        fn reset(zelf: PyRef<Self>, token: PyContextTokenRef, vm: &VirtualMachine) -> PyResult<()> {
+            if token.used.load() {
+                return Err(
+                    vm.new_runtime_error(format!("{} has already been used once", token.repr(vm)?))
+                );
+            }
+            if !token.var.is(&zelf) {
+                return Err(vm.new_value_error(format!(
+                    "{} was created by a different ContextVar",
+                    token.repr(vm)?
+                )));
+            }
+            let ctx = current_context(vm);
+            if !token.context.is(&ctx) {
+                return Err(vm.new_value_error(format!(
+                    "{} was created in a different Context",
+                    token.repr(vm)?
+                )));
+            }
+            let mut vars = ctx.vars.write();
+            match &token.old_value {
+                Some(old) => {
+                    vars.insert(zelf.id, (zelf.clone(), old.clone()));
+                }
+                None => {
+                    vars.remove(&zelf.id);
+                }
+            }
+            token.used.store(true);
+            Ok(())
+        }
+
+        #[pymethod(magic)]
+        fn repr(&self) -> String {
+            format!("<ContextVar name={:?}>", self.name.as_str())
+        }
+    }
+
+    /// The sentinel type backing `Token.MISSING`, returned from `Token.old_value`
+    /// when the variable had no value before the corresponding `set()`.
+    #[pyattr(name = "_TokenMissingType")]
+    #[pyclass(module = "contextvars", name = "_TokenMissingType")]
+    #[derive(Debug)]
+    pub struct PyContextTokenMissing;
+
+    impl PyValue for PyContextTokenMissing {
+        fn class(_vm: &VirtualMachine) -> &PyTypeRef {
+            Self::static_type()
+        }
+    }
+
+    #[pyimpl]
+    impl PyContextTokenMissing {
+        #[pymethod(magic)]
+        fn repr(&self) -> String {
+            "<Token.MISSING>".to_owned()
+        }
+    }
+
+    rustpython_common::static_cell!(
+        static MISSING: PyObjectRef;
+    );
+
+    pub(crate) fn missing(vm: &VirtualMachine) -> PyObjectRef {
+        MISSING
+            .get_or_init(|| PyContextTokenMissing.into_ref(vm).into())
+            .clone()
+    }
+
+    #[pyattr]
+    #[pyclass(module = "contextvars", name = "Token")]
+    #[derive(Debug)]
+    pub struct PyContextToken {
+        var: PyContextVarRef,
+        old_value: Option<PyObjectRef>,
+        context: PyContextRef,
+        used: AtomicCell<bool>,
+    }
+
+    pub type PyContextTokenRef = PyRef<PyContextToken>;
+
+    impl PyValue for PyContextToken {
+        fn class(_vm: &VirtualMachine) -> &PyTypeRef {
+            Self::static_type()
+        }
+    }
+
+    #[pyimpl]
+    impl PyContextToken {
+        #[pyproperty]
+        fn var(&self) -> PyContextVarRef {
+            self.var.clone()
+        }
+
+        #[pyproperty(name = "old_value")]
+        fn old_value(&self, vm: &VirtualMachine) -> PyObjectRef {
+            self.old_value.clone().unwrap_or_else(|| missing(vm))
+        }
+
+        #[pymethod(magic)]
+        fn repr(&self, vm: &VirtualMachine) -> PyResult<String> {
+            let old_value = match &self.old_value {
+                Some(v) => vm.to_repr(v)?.as_str().to_owned(),
+                None => "<Token.MISSING>".to_owned(),
+            };
+            Ok(format!(
+                "<Token var={} old_value={}>",
+                self.var.repr(),
+                old_value
+            ))
+        }
+    }
+
+    // `thread_local!` so each OS thread has its own active context, matching
+    // CPython (contextvars are never implicitly shared across threads).
+    thread_local! {
+        static CONTEXT_STACK: PyRwLock<Vec<PyContextRef>> = PyRwLock::new(Vec::new());
+    }
+
+    /// Mirrors CPython's `PyContextVar_Get`: the low-level lookup that
+    /// `ContextVar.get` builds on. Returns `Ok(Some(value))` if `var` has a
+    /// value in the active context, `Ok(default)` if not (falling back to
+    /// `var`'s own default when `default` is `None`), and never raises
+    /// `LookupError` itself — that's left to the Python-level `get()`.
+    pub(crate) fn context_var_get(
+        var: &PyContextVarRef,
+        default: Option<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<Option<PyObjectRef>> {
+        let ctx = current_context(vm);
+        let found = ctx.vars.read().get(&var.id).map(|(_, v)| v.clone());
+        if found.is_some() {
+            return Ok(found);
+        }
+        Ok(default.or_else(|| var.default.clone()))
+    }
+
+    fn current_context(vm: &VirtualMachine) -> PyContextRef {
+        if let Some(top) = CONTEXT_STACK.with(|stack| stack.read().last().cloned()) {
+            return top;
+        }
+        let ctx = PyContext::default().into_ref(vm);
+        CONTEXT_STACK.with(|stack| stack.write().push(ctx.clone()));
+        ctx
+    }
+
+    #[pyfunction]
+    fn copy_context(vm: &VirtualMachine) -> PyContextRef {
+        let vars = current_context(vm).vars.read().clone();
+        PyContext {
+            vars: PyRwLock::new(vars),
+            entered: AtomicCell::new(false),
+        }
+        .into_ref(vm)
+    }
+
+    /// The full state of the active context's variables, cloned cheaply
+    /// (every value is just an `Rc`/`PyObjectRef` bump) so native stdlib
+    /// modules (e.g. a future `decimal`) can save it before a scoped mutation
+    /// and hand it to [`restore_vars`] afterwards, restoring every variable
+    /// at once rather than threading a `Token` through for each one.
+    pub(crate) type ContextVarsSnapshot = HashMap<usize, (PyContextVarRef, PyObjectRef)>;
+
+    pub(crate) fn snapshot_vars(vm: &VirtualMachine) -> ContextVarsSnapshot {
+        current_context(vm).vars.read().clone()
+    }
+
+    pub(crate) fn restore_vars(vm: &VirtualMachine, snapshot: ContextVarsSnapshot) {
+        *current_context(vm).vars.write() = snapshot;
+    }
+}
@@ -11,6 +11,7 @@ mod binascii;
 mod bisect;
 mod codecs;
 mod collections;
+mod contextvars;
 mod csv;
 mod dis;
 mod errno;
@@ -109,6 +110,7 @@ pub fn get_module_inits() -> StdlibMap {
             "_bisect" => bisect::make_module,
             "_codecs" => codecs::make_module,
             "_collections" => collections::make_module,
+            "_contextvars" => contextvars::make_module,
             "_csv" => csv::make_module,
             "dis" => dis::make_module,
             "errno" => errno::make_module,
@@ -2,27 +2,98 @@ pub(crate) use _warnings::make_module;
 
 #[pymodule]
 mod _warnings {
-    use crate::builtins::pystr::PyStrRef;
     use crate::builtins::pytype::PyTypeRef;
+    use crate::frame::FrameRef;
     use crate::function::OptionalArg;
     use crate::vm::VirtualMachine;
-    use crate::{PyResult, TypeProtocol};
+    use crate::{PyObjectRef, PyResult, TypeProtocol};
 
     #[derive(FromArgs)]
     struct WarnArgs {
         #[pyarg(positional)]
-        message: PyStrRef,
+        message: PyObjectRef,
         #[pyarg(any, optional)]
         category: OptionalArg<PyTypeRef>,
         #[pyarg(any, optional)]
         stacklevel: OptionalArg<u32>,
     }
 
+    /// Mirrors CPython's check in `warnings.c`: frozen `importlib` frames are
+    /// never a meaningful `stacklevel` target (the user didn't write them),
+    /// so they're skipped over while walking up the stack in `setup_context`.
+    fn is_internal_frame(frame: &FrameRef) -> bool {
+        frame.code.source_path.as_str().starts_with("<frozen importlib")
+    }
+
+    /// Walks `stacklevel` frames up from the top of the VM's frame stack
+    /// (skipping internal importlib frames along the way, same as CPython's
+    /// `setup_context` in `Python/warnings.c`), and returns the `(filename,
+    /// lineno, module_name)` of the frame `warn()` should be attributed to.
+    /// `stacklevel <= 1` means "the caller of `warn()` itself", since `warn`
+    /// is a native function and doesn't push its own frame onto the stack.
+    ///
+    /// Unlike CPython's C `setup_context`, this doesn't also return a
+    /// `registry`: this module doesn't export `filters`/`_onceregistry`, so
+    /// `Lib/warnings.py`'s `from _warnings import (filters, ...)` always
+    /// `ImportError`s and falls back to its own pure-Python `warn`/
+    /// `warn_explicit`, which already do full `__warningregistry__`-based
+    /// "once"/"default"/"module" dedup themselves. `warn` below is only
+    /// reachable via a direct `import _warnings; _warnings.warn(...)`, not
+    /// through the public `warnings.warn()` API, so it has no registry of
+    /// its own to consult or update.
+    fn setup_context(stacklevel: u32, vm: &VirtualMachine) -> (String, usize, String) {
+        let frames = vm.frames.borrow();
+        let mut remaining = stacklevel.max(1) - 1;
+        let mut chosen = frames.last();
+        for frame in frames.iter().rev().skip(1) {
+            if remaining == 0 {
+                break;
+            }
+            if is_internal_frame(frame) {
+                continue;
+            }
+            chosen = Some(frame);
+            remaining -= 1;
+        }
+        match chosen {
+            Some(frame) => {
+                let filename = frame.code.source_path.as_str().to_owned();
+                let lineno = frame.current_location().row();
+                let module = frame
+                    .globals
+                    .get_item_option("__name__", vm)
+                    .ok()
+                    .flatten()
+                    .and_then(|name| name.downcast::<crate::builtins::PyStr>().ok())
+                    .map_or_else(|| "<string>".to_owned(), |name| name.as_str().to_owned());
+                (filename, lineno, module)
+            }
+            None => ("sys".to_owned(), 1, "sys".to_owned()),
+        }
+    }
+
+    /// Looks up the `warnings` module's current `showwarning`, the same hook
+    /// real CPython's `_warnings.warn` dispatches through so a user-replaced
+    /// `warnings.showwarning` (e.g. to capture output in tests) actually
+    /// gets called. Falls back to `None` if the module or the attribute
+    /// can't be found, which happens early in interpreter startup before
+    /// `warnings` has been imported.
+    fn current_show_warning(vm: &VirtualMachine) -> Option<crate::PyObjectRef> {
+        vm.import("warnings", None, 0)
+            .and_then(|module| vm.get_attribute(module, "showwarning"))
+            .ok()
+    }
+
     #[pyfunction]
     fn warn(args: WarnArgs, vm: &VirtualMachine) -> PyResult<()> {
-        // TODO: Implement correctly
         let level = args.stacklevel.unwrap_or(1);
-        let category = if let OptionalArg::Present(category) = args.category {
+        // A message that's already a `Warning` instance dictates its own
+        // category (overriding whatever `category=` was passed), exactly
+        // like CPython's `warnings.warn`; only a plain message falls back to
+        // the `category` argument (or `UserWarning`).
+        let category = if args.message.isinstance(&vm.ctx.exceptions.warning) {
+            args.message.clone_class()
+        } else if let OptionalArg::Present(category) = args.category {
             if !category.issubclass(&vm.ctx.exceptions.warning) {
                 return Err(vm.new_type_error(format!(
                     "category must be a Warning subclass, not '{}'",
@@ -33,7 +104,41 @@ mod _warnings {
         } else {
             vm.ctx.exceptions.user_warning.clone()
         };
-        eprintln!("level:{}: {}: {}", level, category.name, args.message);
+        let (filename, lineno, _module) = setup_context(level, vm);
+
+        // The message shown/stored is always an instance of `category`: a
+        // string message gets wrapped via `category(message)`, while an
+        // already-instantiated warning is used as-is.
+        let message = if args.message.isinstance(&vm.ctx.exceptions.warning) {
+            args.message
+        } else {
+            vm.invoke(category.as_object(), vec![args.message])?
+        };
+
+        match current_show_warning(vm) {
+            Some(show_warning) => {
+                if !vm.is_callable(&show_warning) {
+                    return Err(vm.new_type_error(
+                        "warnings.showwarning() must be set to a function or method".to_owned(),
+                    ));
+                }
+                vm.invoke(
+                    &show_warning,
+                    vec![
+                        message,
+                        category.into_object(),
+                        vm.ctx.new_str(filename),
+                        vm.ctx.new_int(lineno),
+                        vm.ctx.none(),
+                        vm.ctx.none(),
+                    ],
+                )?;
+            }
+            None => {
+                let text = vm.to_str(&message)?;
+                eprintln!("{}:{}: {}: {}", filename, lineno, category.name, text);
+            }
+        }
         Ok(())
     }
 }
@@ -5,17 +5,21 @@ use std::ptr::{null, null_mut};
 use winapi::shared::winerror;
 use winapi::um::winnt::HANDLE;
 use winapi::um::{
-    fileapi, handleapi, namedpipeapi, processenv, processthreadsapi, synchapi, winbase, winnt,
-    winuser,
+    fileapi, handleapi, ioapiset, minwinbase, namedpipeapi, processenv, processthreadsapi,
+    securitybaseapi, synchapi, sysinfoapi, winbase, wincon, winnls, winnt, winuser,
 };
 
 use super::os::errno_err;
 use crate::builtins::dict::{PyDictRef, PyMapping};
 use crate::builtins::pystr::PyStrRef;
+use crate::builtins::pytype::PyTypeRef;
+use crate::byteslike::PyBytesLike;
+use crate::common::lock::PyMutex;
 use crate::exceptions::IntoPyException;
 use crate::function::OptionalArg;
 use crate::VirtualMachine;
-use crate::{PyObjectRef, PyResult, PySequence, TryFromObject};
+use crate::{PyClassImpl, PyObjectRef, PyRef, PyResult, PySequence, PyValue, TryFromObject};
+use crossbeam_utils::atomic::AtomicCell;
 
 fn GetLastError() -> u32 {
     unsafe { winapi::um::errhandlingapi::GetLastError() }
@@ -39,6 +43,11 @@ impl Convertable for i32 {
         *self == 0
     }
 }
+impl Convertable for u32 {
+    fn is_err(&self) -> bool {
+        *self == 0
+    }
+}
 
 fn cvt<T: Convertable>(vm: &VirtualMachine, res: T) -> PyResult<T> {
     if res.is_err() {
@@ -48,6 +57,29 @@ fn cvt<T: Convertable>(vm: &VirtualMachine, res: T) -> PyResult<T> {
     }
 }
 
+/// Verify a std handle destined for a child process (via `STARTF_USESTDHANDLES`)
+/// actually has `HANDLE_FLAG_INHERIT` set. Without this, `CreateProcess(...,
+/// inherit_handles=True, ...)` silently hands the child a pipe it can't see,
+/// which is the most common cause of subprocess pipes hanging on Windows.
+fn check_handle_inheritable(handle: usize, vm: &VirtualMachine) -> PyResult<()> {
+    if handle == 0 || handle as HANDLE == handleapi::INVALID_HANDLE_VALUE {
+        return Ok(());
+    }
+    let mut flags = 0;
+    cvt(vm, unsafe {
+        handleapi::GetHandleInformation(handle as HANDLE, &mut flags)
+    })?;
+    if flags & winbase::HANDLE_FLAG_INHERIT == 0 {
+        return Err(vm.new_os_error(format!(
+            "handle {} is not inheritable but inherit_handles=True and \
+             STARTF_USESTDHANDLES were requested; pass an inheritable handle \
+             or omit it from startup_info",
+            handle
+        )));
+    }
+    Ok(())
+}
+
 fn _winapi_CloseHandle(handle: usize, vm: &VirtualMachine) -> PyResult<()> {
     cvt(vm, unsafe { handleapi::CloseHandle(handle as HANDLE) }).map(drop)
 }
@@ -69,6 +101,402 @@ fn _winapi_CreatePipe(
     Ok((read as usize, write as usize))
 }
 
+fn _winapi_CreateNamedPipe(
+    name: PyStrRef,
+    open_mode: u32,
+    pipe_mode: u32,
+    max_instances: u32,
+    out_buffer: u32,
+    in_buffer: u32,
+    default_timeout: u32,
+    _security_attrs: PyObjectRef,
+    vm: &VirtualMachine,
+) -> PyResult<usize> {
+    let name =
+        widestring::WideCString::from_str(name.as_str()).map_err(|err| err.into_pyexception(vm))?;
+    let handle = unsafe {
+        namedpipeapi::CreateNamedPipeW(
+            name.as_ptr(),
+            open_mode,
+            pipe_mode,
+            max_instances,
+            out_buffer,
+            in_buffer,
+            default_timeout,
+            null_mut(),
+        )
+    };
+    cvt(vm, handle).map(husize)
+}
+
+fn _winapi_GetLogicalDrives(vm: &VirtualMachine) -> PyResult<u32> {
+    cvt(vm, unsafe { fileapi::GetLogicalDrives() })
+}
+
+fn _winapi_GetDriveType(root_path: PyStrRef, vm: &VirtualMachine) -> PyResult<u32> {
+    let root_path = widestring::WideCString::from_str(root_path.as_str())
+        .map_err(|err| err.into_pyexception(vm))?;
+    Ok(unsafe { fileapi::GetDriveTypeW(root_path.as_ptr()) })
+}
+
+fn _winapi_GetFileAttributes(path: PyStrRef, vm: &VirtualMachine) -> PyResult<u32> {
+    let path =
+        widestring::WideCString::from_str(path.as_str()).map_err(|err| err.into_pyexception(vm))?;
+    let attributes = unsafe { fileapi::GetFileAttributesW(path.as_ptr()) };
+    if attributes == fileapi::INVALID_FILE_ATTRIBUTES {
+        Err(errno_err(vm))
+    } else {
+        Ok(attributes)
+    }
+}
+
+fn _winapi_SetFileAttributes(path: PyStrRef, attributes: u32, vm: &VirtualMachine) -> PyResult<()> {
+    let path =
+        widestring::WideCString::from_str(path.as_str()).map_err(|err| err.into_pyexception(vm))?;
+    let ret = unsafe { fileapi::SetFileAttributesW(path.as_ptr(), attributes) };
+    if ret == 0 {
+        Err(errno_err(vm))
+    } else {
+        Ok(())
+    }
+}
+
+// `subprocess` uses this on Windows to open `NUL` (and other real paths)
+// for the pipe ends it doesn't redirect, the same way `os.open` backs a
+// `DEVNULL` file descriptor on POSIX. `security_attrs` and `template_file`
+// are accepted for signature compatibility with CPython's `_winapi.CreateFile`
+// but unused here, matching `CreateNamedPipe`'s `_security_attrs` above.
+#[allow(clippy::too_many_arguments)]
+fn _winapi_CreateFile(
+    name: PyStrRef,
+    desired_access: u32,
+    share_mode: u32,
+    _security_attrs: PyObjectRef,
+    creation_disposition: u32,
+    flags_and_attributes: u32,
+    _template_file: OptionalArg<usize>,
+    vm: &VirtualMachine,
+) -> PyResult<usize> {
+    let name =
+        widestring::WideCString::from_str(name.as_str()).map_err(|err| err.into_pyexception(vm))?;
+    let handle = unsafe {
+        fileapi::CreateFileW(
+            name.as_ptr(),
+            desired_access,
+            share_mode,
+            null_mut(),
+            creation_disposition,
+            flags_and_attributes,
+            null_mut(),
+        )
+    };
+    cvt(vm, handle).map(husize)
+}
+
+// Expands `%VAR%` references by the platform's own rules (case-insensitive
+// names, undefined references left untouched); `ntpath.expandvars` does its
+// own parsing and does not call this. Like `GetTokenInformation`, the first call
+// reports the buffer size actually needed (as its return value, here), so
+// the buffer only needs to grow when the initial guess is too small.
+fn _winapi_ExpandEnvironmentStrings(src: PyStrRef, vm: &VirtualMachine) -> PyResult<String> {
+    let src =
+        widestring::WideCString::from_str(src.as_str()).map_err(|err| err.into_pyexception(vm))?;
+    let mut buf = vec![0u16; 256];
+    loop {
+        let len = unsafe {
+            winbase::ExpandEnvironmentStringsW(src.as_ptr(), buf.as_mut_ptr(), buf.len() as u32)
+        } as usize;
+        if len == 0 {
+            return Err(errno_err(vm));
+        }
+        if len <= buf.len() {
+            // `len` counts the terminating NUL.
+            return Ok(String::from_utf16_lossy(&buf[..len - 1]));
+        }
+        buf.resize(len, 0);
+    }
+}
+
+/// Reports what `GetSystemInfo` returns in one call, including the allocation
+/// granularity a Windows mmap offset must be a multiple of (typically larger
+/// than `dwPageSize`); nothing in this tree calls it. Returned in the
+/// same field order as CPython's `mmap.c` reads them off the `SYSTEM_INFO`
+/// struct: page size, allocation granularity, processor count, then the
+/// minimum/maximum addresses applications may use.
+fn _winapi_GetSystemInfo() -> (u32, u32, u32, usize, usize) {
+    let mut info = unsafe { std::mem::zeroed() };
+    unsafe { sysinfoapi::GetSystemInfo(&mut info) };
+    (
+        info.dwPageSize,
+        info.dwAllocationGranularity,
+        info.dwNumberOfProcessors,
+        info.lpMinimumApplicationAddress as usize,
+        info.lpMaximumApplicationAddress as usize,
+    )
+}
+
+/// Resolves a DOS device name (e.g. `"C:"` for a `subst`-mapped drive, or a
+/// device name like `"PhysicalDrive0"`) to its target path(s) -- the kind of
+/// indirection the standard path APIs (`GetFullPathName`, etc.) transparently
+/// follow but never expose directly. `QueryDosDeviceW` fills its output
+/// buffer with a multi-string: each target path is NUL-terminated, and the
+/// whole list ends with an extra NUL, so a device with more than one target
+/// (a drive `subst`ed onto another `subst`ed drive, for instance) reports all
+/// of them. Like `GetTokenInformation`, the buffer only needs to grow when
+/// the initial guess is too small.
+fn _winapi_QueryDosDevice(device_name: PyStrRef, vm: &VirtualMachine) -> PyResult<Vec<String>> {
+    let device_name = widestring::WideCString::from_str(device_name.as_str())
+        .map_err(|err| err.into_pyexception(vm))?;
+    let mut buf = vec![0u16; 256];
+    loop {
+        let len = unsafe {
+            fileapi::QueryDosDeviceW(device_name.as_ptr(), buf.as_mut_ptr(), buf.len() as u32)
+        } as usize;
+        if len != 0 {
+            // Drop the terminating double-NUL, then split the remaining
+            // single-NUL-terminated strings.
+            return Ok(buf[..len - 2]
+                .split(|&c| c == 0)
+                .map(String::from_utf16_lossy)
+                .collect());
+        }
+        if GetLastError() != winerror::ERROR_INSUFFICIENT_BUFFER {
+            return Err(errno_err(vm));
+        }
+        buf.resize(buf.len() * 2, 0);
+    }
+}
+
+/// Wraps an `OVERLAPPED` struct (boxed so its address stays stable across
+/// moves, since the kernel writes into it asynchronously) together with the
+/// handle the pending I/O was issued against, so `GetResult`/`cancel` don't
+/// need that handle passed in separately every call. Matches real CPython's
+/// `_winapi.Overlapped`, used by `multiprocessing`'s overlapped pipe I/O on
+/// Windows.
+#[pyclass(module = "_winapi", name = "Overlapped")]
+struct PyOverlapped {
+    overlapped: PyMutex<Box<winapi::um::minwinbase::OVERLAPPED>>,
+    handle: AtomicCell<usize>,
+    // Keeps an in-flight overlapped `ReadFile`/`WriteFile`'s buffer alive
+    // for as long as the kernel might still be reading from or writing into
+    // it (it can't be freed before the operation completes), and, for
+    // reads, is what `GetResult` trims to the transferred length and hands
+    // back; `is_read` distinguishes that case from a write, which only
+    // needs the buffer kept alive and reports a byte count instead.
+    buf: PyMutex<Option<Vec<u8>>>,
+    is_read: AtomicCell<bool>,
+}
+
+impl std::fmt::Debug for PyOverlapped {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PyOverlapped").finish()
+    }
+}
+
+impl PyValue for PyOverlapped {
+    fn class(_vm: &VirtualMachine) -> &PyTypeRef {
+        Self::static_type()
+    }
+}
+
+#[pyimpl]
+impl PyOverlapped {
+    #[pyslot]
+    fn tp_new(
+        cls: PyTypeRef,
+        event: OptionalArg<usize>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyRef<Self>> {
+        // A caller-supplied `0`/omitted event means the caller has no event
+        // object of its own, so only a genuinely nonzero handle is
+        // reused; otherwise a fresh manual-reset event is created, matching
+        // `_overlapped.Overlapped()`'s default of auto-creating one.
+        let event = match event {
+            OptionalArg::Present(h) if h != 0 => h,
+            _ => cvt(vm, unsafe {
+                synchapi::CreateEventW(null_mut(), 1, 0, null_mut())
+            })
+            .map(husize)?,
+        };
+        let mut overlapped = Box::new(unsafe { std::mem::zeroed::<minwinbase::OVERLAPPED>() });
+        overlapped.hEvent = event as HANDLE;
+        PyOverlapped {
+            overlapped: PyMutex::new(overlapped),
+            handle: AtomicCell::new(0),
+            buf: PyMutex::new(None),
+            is_read: AtomicCell::new(false),
+        }
+        .into_ref_with_type(vm, cls)
+    }
+
+    #[pyproperty]
+    fn event(&self) -> usize {
+        husize(self.overlapped.lock().hEvent)
+    }
+
+    pub(super) fn as_raw(&self) -> *mut winapi::um::minwinbase::OVERLAPPED {
+        &mut **self.overlapped.lock() as *mut _
+    }
+
+    #[pymethod(name = "GetResult")]
+    fn get_result(&self, wait: OptionalArg<i32>, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+        let mut transferred = 0u32;
+        let ret = unsafe {
+            ioapiset::GetOverlappedResult(
+                self.handle.load() as _,
+                self.as_raw(),
+                &mut transferred,
+                wait.unwrap_or(0),
+            )
+        };
+        if ret == 0 {
+            return Err(errno_err(vm));
+        }
+        Ok(match self.buf.lock().take() {
+            Some(mut buf) if self.is_read.load() => {
+                buf.truncate(transferred as usize);
+                vm.ctx.new_bytes(buf)
+            }
+            _ => vm.ctx.new_int(transferred),
+        })
+    }
+
+    #[pymethod]
+    fn cancel(&self, vm: &VirtualMachine) -> PyResult<()> {
+        let ret = unsafe { ioapiset::CancelIoEx(self.handle.load() as _, self.as_raw()) };
+        if ret == 0 && GetLastError() != winerror::ERROR_NOT_FOUND {
+            return Err(errno_err(vm));
+        }
+        Ok(())
+    }
+}
+
+// `multiprocessing.connection`'s Windows pipe transport reads/writes through
+// these, picking `overlapped=True` for its non-blocking `PipeConnection`
+// and leaving it off for blocking use. A broken pipe is reported through the
+// normal `errno_err` path, surfacing as `ERROR_BROKEN_PIPE` like CPython's
+// `_winapi.ReadFile`/`WriteFile` document.
+fn _winapi_ReadFile(
+    handle: usize,
+    size: u32,
+    overlapped: OptionalArg<bool>,
+    vm: &VirtualMachine,
+) -> PyResult<(PyObjectRef, u32)> {
+    let buf = vec![0u8; size as usize];
+    let mut read_bytes = 0u32;
+    if overlapped.unwrap_or(false) {
+        let ov = PyOverlapped {
+            overlapped: PyMutex::new(Box::new(unsafe { std::mem::zeroed() })),
+            handle: AtomicCell::new(handle),
+            buf: PyMutex::new(Some(buf)),
+            is_read: AtomicCell::new(true),
+        }
+        .into_ref(vm);
+        let ptr = ov.buf.lock().as_mut().unwrap().as_mut_ptr();
+        let ret =
+            unsafe { fileapi::ReadFile(handle as _, ptr as _, size, &mut read_bytes, ov.as_raw()) };
+        return if ret != 0 {
+            Ok((ov.into_object(), 0))
+        } else {
+            let err = GetLastError();
+            if err == winerror::ERROR_IO_PENDING {
+                Ok((ov.into_object(), err))
+            } else {
+                Err(errno_err(vm))
+            }
+        };
+    }
+    let mut buf = buf;
+    let ret = unsafe {
+        fileapi::ReadFile(
+            handle as _,
+            buf.as_mut_ptr() as _,
+            size,
+            &mut read_bytes,
+            null_mut(),
+        )
+    };
+    if ret == 0 {
+        return Err(errno_err(vm));
+    }
+    buf.truncate(read_bytes as usize);
+    Ok((vm.ctx.new_bytes(buf), 0))
+}
+
+fn _winapi_WriteFile(
+    handle: usize,
+    data: PyBytesLike,
+    overlapped: OptionalArg<bool>,
+    vm: &VirtualMachine,
+) -> PyResult<(PyObjectRef, u32)> {
+    let buf = data.to_cow().into_owned();
+    let mut written = 0u32;
+    if overlapped.unwrap_or(false) {
+        let ov = PyOverlapped {
+            overlapped: PyMutex::new(Box::new(unsafe { std::mem::zeroed() })),
+            handle: AtomicCell::new(handle),
+            buf: PyMutex::new(Some(buf)),
+            is_read: AtomicCell::new(false),
+        }
+        .into_ref(vm);
+        let (ptr, len) = {
+            let guard = ov.buf.lock();
+            let buf = guard.as_ref().unwrap();
+            (buf.as_ptr(), buf.len() as u32)
+        };
+        let ret =
+            unsafe { fileapi::WriteFile(handle as _, ptr as _, len, &mut written, ov.as_raw()) };
+        return if ret != 0 {
+            Ok((ov.into_object(), 0))
+        } else {
+            let err = GetLastError();
+            if err == winerror::ERROR_IO_PENDING {
+                Ok((ov.into_object(), err))
+            } else {
+                Err(errno_err(vm))
+            }
+        };
+    }
+    let ret = unsafe {
+        fileapi::WriteFile(
+            handle as _,
+            buf.as_ptr() as _,
+            buf.len() as u32,
+            &mut written,
+            null_mut(),
+        )
+    };
+    if ret == 0 {
+        return Err(errno_err(vm));
+    }
+    Ok((vm.ctx.new_int(written), 0))
+}
+
+// This free function stays alongside `PyOverlapped::get_result` rather than
+// being replaced by it: callers that built their own OVERLAPPED struct (e.g.
+// via `ctypes`) pass its raw address as `overlapped` here instead of going
+// through a `PyOverlapped`, the same way every other opaque Win32 handle in
+// this file is passed around as a `usize`.
+fn _winapi_GetOverlappedResult(
+    handle: usize,
+    overlapped: usize,
+    wait: i32,
+    vm: &VirtualMachine,
+) -> PyResult<(u32, u32)> {
+    let mut transferred = 0u32;
+    let ret = unsafe {
+        ioapiset::GetOverlappedResult(handle as _, overlapped as *mut _, &mut transferred, wait)
+    };
+    if ret == 0 {
+        let err = GetLastError();
+        if wait == 0 && err == winerror::ERROR_IO_INCOMPLETE {
+            return Ok((transferred, err));
+        }
+        return Err(errno_err(vm));
+    }
+    Ok((transferred, 0))
+}
+
 fn _winapi_DuplicateHandle(
     (src_process, src): (usize, usize),
     target_process: usize,
@@ -96,6 +524,28 @@ fn _winapi_GetCurrentProcess() -> usize {
     unsafe { processthreadsapi::GetCurrentProcess() as usize }
 }
 
+fn _winapi_GetCurrentProcessId() -> u32 {
+    unsafe { processthreadsapi::GetCurrentProcessId() }
+}
+
+fn _winapi_GetACP() -> u32 {
+    unsafe { winnls::GetACP() }
+}
+
+// With no console attached, these legitimately return 0 rather than
+// failing, so unlike most wrappers here there's no `GetLastError`/`cvt`
+// check to make.
+fn _winapi_GetConsoleCP() -> u32 {
+    unsafe { wincon::GetConsoleCP() }
+}
+
+fn _winapi_GetConsoleOutputCP() -> u32 {
+    unsafe { wincon::GetConsoleOutputCP() }
+}
+
+// NOTE: this module is built on the `winapi` crate (not `windows`), so
+// `fileapi::GetFileType` takes a raw `HANDLE` directly; there's no generic
+// `P0` parameter to get wrong here.
 fn _winapi_GetFileType(h: usize, vm: &VirtualMachine) -> PyResult<u32> {
     let ret = unsafe { fileapi::GetFileType(h as _) };
     if ret == 0 && GetLastError() != 0 {
@@ -156,6 +606,16 @@ fn _winapi_CreateProcess(
     si_attr!(hStdOutput, usize);
     si_attr!(hStdError, usize);
 
+    if args.inherit_handles != 0 && si.StartupInfo.dwFlags & winbase::STARTF_USESTDHANDLES != 0 {
+        for handle in &[
+            si.StartupInfo.hStdInput,
+            si.StartupInfo.hStdOutput,
+            si.StartupInfo.hStdError,
+        ] {
+            check_handle_inheritable(*handle, vm)?;
+        }
+    }
+
     let mut env = args
         .env_mapping
         .map(|m| getenvironment(m.into_dict(), vm))
@@ -327,6 +787,14 @@ fn _winapi_WaitForSingleObject(h: usize, ms: u32, vm: &VirtualMachine) -> PyResu
     }
 }
 
+// A raw exit code of `STILL_ACTIVE` (259) is ambiguous: the process may
+// really still be running, or it may have legitimately exited with that
+// code. This wrapper can't disambiguate on its own without changing the
+// meaning of a direct call (e.g. after `_wait` has already blocked on a real
+// `WaitForSingleObject` timeout). Callers that poll without waiting, like
+// `Popen._internal_poll` in `Lib/subprocess.py`, resolve the ambiguity
+// themselves by doing a zero-timeout `WaitForSingleObject` first and only
+// trusting this result once that reports the handle as signaled.
 fn _winapi_GetExitCodeProcess(h: usize, vm: &VirtualMachine) -> PyResult<u32> {
     let mut ec = 0;
     cvt(vm, unsafe {
@@ -342,23 +810,128 @@ fn _winapi_TerminateProcess(h: usize, exit_code: u32, vm: &VirtualMachine) -> Py
     .map(drop)
 }
 
+fn _winapi_OpenProcessToken(
+    process: usize,
+    desired_access: u32,
+    vm: &VirtualMachine,
+) -> PyResult<usize> {
+    let mut token = null_mut();
+    cvt(vm, unsafe {
+        processthreadsapi::OpenProcessToken(process as _, desired_access, &mut token)
+    })?;
+    Ok(token as usize)
+}
+
+// `GetTokenInformation` is a variable-length-output API: the first call with
+// a zero-size buffer reports how large a buffer is actually needed (failing
+// with `ERROR_INSUFFICIENT_BUFFER`, which isn't a real error here), then the
+// second call with a buffer of that size fills it in.
+fn _winapi_GetTokenInformation(
+    token: usize,
+    info_class: u32,
+    vm: &VirtualMachine,
+) -> PyResult<Vec<u8>> {
+    let info_class = info_class as winnt::TOKEN_INFORMATION_CLASS;
+    let mut size = 0u32;
+    let ret = unsafe {
+        securitybaseapi::GetTokenInformation(token as _, info_class, null_mut(), 0, &mut size)
+    };
+    if ret == 0 && GetLastError() != winerror::ERROR_INSUFFICIENT_BUFFER {
+        return Err(errno_err(vm));
+    }
+    let mut buf = vec![0u8; size as usize];
+    cvt(vm, unsafe {
+        securitybaseapi::GetTokenInformation(
+            token as _,
+            info_class,
+            buf.as_mut_ptr() as _,
+            size,
+            &mut size,
+        )
+    })?;
+    Ok(buf)
+}
+
+// Unlike `GetTokenInformation`, `GetNamedPipeHandleState` has no way to probe
+// the username buffer size up front, so (matching CPython's `_winapi.c`) this
+// uses a fixed `MAX_PATH`-sized buffer and decodes up to the embedded NUL.
+fn _winapi_GetNamedPipeHandleState(
+    named_pipe: usize,
+    vm: &VirtualMachine,
+) -> PyResult<(u32, u32, u32, u32, String)> {
+    let mut state = 0;
+    let mut cur_instances = 0;
+    let mut max_collection_count = 0;
+    let mut collect_data_timeout = 0;
+    let mut username = vec![0u16; winnt::MAX_PATH];
+    cvt(vm, unsafe {
+        namedpipeapi::GetNamedPipeHandleState(
+            named_pipe as _,
+            &mut state,
+            &mut cur_instances,
+            &mut max_collection_count,
+            &mut collect_data_timeout,
+            username.as_mut_ptr(),
+            username.len() as u32,
+        )
+    })?;
+    let len = username.iter().position(|&c| c == 0).unwrap_or(0);
+    let username = String::from_utf16_lossy(&username[..len]);
+    Ok((
+        state,
+        cur_instances,
+        max_collection_count,
+        collect_data_timeout,
+        username,
+    ))
+}
+
 pub fn make_module(vm: &VirtualMachine) -> PyObjectRef {
     let ctx = &vm.ctx;
     py_module!(vm, "_winapi", {
         "CloseHandle" => named_function!(ctx, _winapi, CloseHandle),
         "GetStdHandle" => named_function!(ctx, _winapi, GetStdHandle),
         "CreatePipe" => named_function!(ctx, _winapi, CreatePipe),
+        "ReadFile" => named_function!(ctx, _winapi, ReadFile),
+        "WriteFile" => named_function!(ctx, _winapi, WriteFile),
         "DuplicateHandle" => named_function!(ctx, _winapi, DuplicateHandle),
+        "CreateNamedPipe" => named_function!(ctx, _winapi, CreateNamedPipe),
+        "GetLogicalDrives" => named_function!(ctx, _winapi, GetLogicalDrives),
+        "GetDriveType" => named_function!(ctx, _winapi, GetDriveType),
+        "GetFileAttributes" => named_function!(ctx, _winapi, GetFileAttributes),
+        "SetFileAttributes" => named_function!(ctx, _winapi, SetFileAttributes),
+        "CreateFile" => named_function!(ctx, _winapi, CreateFile),
+        "QueryDosDevice" => named_function!(ctx, _winapi, QueryDosDevice),
+        "GetSystemInfo" => named_function!(ctx, _winapi, GetSystemInfo),
+        "ExpandEnvironmentStrings" => named_function!(ctx, _winapi, ExpandEnvironmentStrings),
+        "GetOverlappedResult" => named_function!(ctx, _winapi, GetOverlappedResult),
+        "Overlapped" => PyOverlapped::make_class(ctx),
         "GetCurrentProcess" => named_function!(ctx, _winapi, GetCurrentProcess),
+        "GetCurrentProcessId" => named_function!(ctx, _winapi, GetCurrentProcessId),
+        "GetACP" => named_function!(ctx, _winapi, GetACP),
+        "GetConsoleCP" => named_function!(ctx, _winapi, GetConsoleCP),
+        "GetConsoleOutputCP" => named_function!(ctx, _winapi, GetConsoleOutputCP),
         "CreateProcess" => named_function!(ctx, _winapi, CreateProcess),
         "WaitForSingleObject" => named_function!(ctx, _winapi, WaitForSingleObject),
         "GetExitCodeProcess" => named_function!(ctx, _winapi, GetExitCodeProcess),
         "TerminateProcess" => named_function!(ctx, _winapi, TerminateProcess),
+        "OpenProcessToken" => named_function!(ctx, _winapi, OpenProcessToken),
+        "GetTokenInformation" => named_function!(ctx, _winapi, GetTokenInformation),
+        "GetNamedPipeHandleState" => named_function!(ctx, _winapi, GetNamedPipeHandleState),
+
+        "PROCESS_QUERY_INFORMATION" => ctx.new_int(winnt::PROCESS_QUERY_INFORMATION),
+        "PROCESS_QUERY_LIMITED_INFORMATION" => {
+            ctx.new_int(winnt::PROCESS_QUERY_LIMITED_INFORMATION)
+        }
+        "TOKEN_QUERY" => ctx.new_int(winnt::TOKEN_QUERY),
+        "TokenElevation" => ctx.new_int(winnt::TokenElevation as u32),
 
         "WAIT_OBJECT_0" => ctx.new_int(winbase::WAIT_OBJECT_0),
         "WAIT_ABANDONED" => ctx.new_int(winbase::WAIT_ABANDONED),
         "WAIT_ABANDONED_0" => ctx.new_int(winbase::WAIT_ABANDONED_0),
         "WAIT_TIMEOUT" => ctx.new_int(winerror::WAIT_TIMEOUT),
+        "ERROR_IO_PENDING" => ctx.new_int(winerror::ERROR_IO_PENDING),
+        "ERROR_BROKEN_PIPE" => ctx.new_int(winerror::ERROR_BROKEN_PIPE),
         "INFINITE" => ctx.new_int(winbase::INFINITE),
         "CREATE_NEW_CONSOLE" => ctx.new_int(winbase::CREATE_NEW_CONSOLE),
         "CREATE_NEW_PROCESS_GROUP" => ctx.new_int(winbase::CREATE_NEW_PROCESS_GROUP),
@@ -379,10 +952,258 @@ pub fn make_module(vm: &VirtualMachine) -> PyObjectRef {
         "CREATE_DEFAULT_ERROR_MODE" => ctx.new_int(winbase::CREATE_DEFAULT_ERROR_MODE),
         "CREATE_BREAKAWAY_FROM_JOB" => ctx.new_int(winbase::CREATE_BREAKAWAY_FROM_JOB),
         "DUPLICATE_SAME_ACCESS" => ctx.new_int(winnt::DUPLICATE_SAME_ACCESS),
+        "GENERIC_READ" => ctx.new_int(winnt::GENERIC_READ),
+        "GENERIC_WRITE" => ctx.new_int(winnt::GENERIC_WRITE),
+        "FILE_SHARE_READ" => ctx.new_int(winnt::FILE_SHARE_READ),
+        "FILE_SHARE_WRITE" => ctx.new_int(winnt::FILE_SHARE_WRITE),
+        "FILE_ATTRIBUTE_NORMAL" => ctx.new_int(winnt::FILE_ATTRIBUTE_NORMAL),
+        "OPEN_EXISTING" => ctx.new_int(fileapi::OPEN_EXISTING),
         "FILE_TYPE_CHAR" => ctx.new_int(winbase::FILE_TYPE_CHAR),
         "FILE_TYPE_DISK" => ctx.new_int(winbase::FILE_TYPE_DISK),
         "FILE_TYPE_PIPE" => ctx.new_int(winbase::FILE_TYPE_PIPE),
         "FILE_TYPE_REMOTE" => ctx.new_int(winbase::FILE_TYPE_REMOTE),
         "FILE_TYPE_UNKNOWN" => ctx.new_int(winbase::FILE_TYPE_UNKNOWN),
+        "PIPE_ACCESS_DUPLEX" => ctx.new_int(winbase::PIPE_ACCESS_DUPLEX),
+        "PIPE_ACCESS_INBOUND" => ctx.new_int(winbase::PIPE_ACCESS_INBOUND),
+        "PIPE_ACCESS_OUTBOUND" => ctx.new_int(winbase::PIPE_ACCESS_OUTBOUND),
+        "PIPE_TYPE_MESSAGE" => ctx.new_int(winbase::PIPE_TYPE_MESSAGE),
+        "PIPE_TYPE_BYTE" => ctx.new_int(winbase::PIPE_TYPE_BYTE),
+        "PIPE_READMODE_MESSAGE" => ctx.new_int(winbase::PIPE_READMODE_MESSAGE),
+        "PIPE_READMODE_BYTE" => ctx.new_int(winbase::PIPE_READMODE_BYTE),
+        "PIPE_WAIT" => ctx.new_int(winbase::PIPE_WAIT),
+        "PIPE_NOWAIT" => ctx.new_int(winbase::PIPE_NOWAIT),
+        "PIPE_UNLIMITED_INSTANCES" => ctx.new_int(winbase::PIPE_UNLIMITED_INSTANCES),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::os::windows::io::AsRawHandle;
+
+    #[test]
+    fn get_file_type_disk() {
+        let file = File::create(std::env::temp_dir().join("rustpython_winapi_test.tmp")).unwrap();
+        let ret = unsafe { fileapi::GetFileType(file.as_raw_handle() as _) };
+        assert_eq!(ret, winbase::FILE_TYPE_DISK);
+    }
+
+    #[test]
+    fn create_named_pipe_roundtrip() {
+        let name =
+            widestring::WideCString::from_str(r"\\.\pipe\rustpython_winapi_test_pipe").unwrap();
+        let handle = unsafe {
+            namedpipeapi::CreateNamedPipeW(
+                name.as_ptr(),
+                winbase::PIPE_ACCESS_DUPLEX,
+                winbase::PIPE_TYPE_MESSAGE | winbase::PIPE_READMODE_MESSAGE | winbase::PIPE_WAIT,
+                1,
+                512,
+                512,
+                0,
+                null_mut(),
+            )
+        };
+        assert_ne!(handle, handleapi::INVALID_HANDLE_VALUE);
+        unsafe { handleapi::CloseHandle(handle) };
+    }
+
+    #[test]
+    fn get_logical_drives_includes_boot_drive() {
+        let mask = unsafe { fileapi::GetLogicalDrives() };
+        assert_ne!(mask, 0);
+
+        let root = widestring::WideCString::from_str(r"C:\").unwrap();
+        let drive_type = unsafe { fileapi::GetDriveTypeW(root.as_ptr()) };
+        assert_ne!(drive_type, winbase::DRIVE_UNKNOWN);
+    }
+
+    #[test]
+    fn get_overlapped_result_on_completed_read() {
+        let mut read = null_mut();
+        let mut write = null_mut();
+        let ret = unsafe { namedpipeapi::CreatePipe(&mut read, &mut write, null_mut(), 0) };
+        assert_ne!(ret, 0);
+
+        let message = b"hi";
+        let mut written = 0u32;
+        let ret = unsafe {
+            fileapi::WriteFile(
+                write,
+                message.as_ptr() as _,
+                message.len() as u32,
+                &mut written,
+                null_mut(),
+            )
+        };
+        assert_ne!(ret, 0);
+
+        let mut overlapped: winapi::um::minwinbase::OVERLAPPED = unsafe { std::mem::zeroed() };
+        let mut buf = [0u8; 2];
+        let mut read_bytes = 0u32;
+        let ret = unsafe {
+            fileapi::ReadFile(
+                read,
+                buf.as_mut_ptr() as _,
+                buf.len() as u32,
+                &mut read_bytes,
+                &mut overlapped,
+            )
+        };
+        assert_ne!(ret, 0);
+
+        let mut transferred = 0u32;
+        let ret =
+            unsafe { ioapiset::GetOverlappedResult(read, &mut overlapped, &mut transferred, 1) };
+        assert_ne!(ret, 0);
+        assert_eq!(transferred, message.len() as u32);
+
+        unsafe {
+            handleapi::CloseHandle(read);
+            handleapi::CloseHandle(write);
+        }
+    }
+
+    #[test]
+    fn overlapped_get_result_reports_transferred_bytes() {
+        use crate::Interpreter;
+
+        Interpreter::default().enter(|vm| {
+            let mut read = null_mut();
+            let mut write = null_mut();
+            let ret = unsafe { namedpipeapi::CreatePipe(&mut read, &mut write, null_mut(), 0) };
+            assert_ne!(ret, 0);
+
+            let message = b"hi";
+            let mut written = 0u32;
+            let ret = unsafe {
+                fileapi::WriteFile(
+                    write,
+                    message.as_ptr() as _,
+                    message.len() as u32,
+                    &mut written,
+                    null_mut(),
+                )
+            };
+            assert_ne!(ret, 0);
+
+            let overlapped = PyOverlapped {
+                overlapped: PyMutex::new(Box::new(unsafe { std::mem::zeroed() })),
+                handle: AtomicCell::new(read as usize),
+                buf: PyMutex::new(None),
+                is_read: AtomicCell::new(false),
+            };
+            let mut buf = [0u8; 2];
+            let mut read_bytes = 0u32;
+            let ret = unsafe {
+                fileapi::ReadFile(
+                    read,
+                    buf.as_mut_ptr() as _,
+                    buf.len() as u32,
+                    &mut read_bytes,
+                    overlapped.as_raw(),
+                )
+            };
+            assert_ne!(ret, 0);
+
+            let transferred = overlapped.get_result(OptionalArg::Present(1), vm).unwrap();
+            let transferred = u32::try_from_object(vm, transferred).unwrap();
+            assert_eq!(transferred, message.len() as u32);
+
+            unsafe {
+                handleapi::CloseHandle(read);
+                handleapi::CloseHandle(write);
+            }
+        })
+    }
+
+    #[test]
+    fn read_write_file_roundtrip_synchronous() {
+        use crate::Interpreter;
+
+        Interpreter::default().enter(|vm| {
+            let mut read = null_mut();
+            let mut write = null_mut();
+            let ret = unsafe { namedpipeapi::CreatePipe(&mut read, &mut write, null_mut(), 0) };
+            assert_ne!(ret, 0);
+
+            let data = vm.ctx.new_bytes(b"hello, pipe".to_vec());
+            let data = PyBytesLike::new(vm, &data).unwrap();
+            let (written, err) =
+                _winapi_WriteFile(write as usize, data, OptionalArg::Missing, vm).unwrap();
+            assert_eq!(err, 0);
+            assert_eq!(
+                u32::try_from_object(vm, written).unwrap(),
+                "hello, pipe".len() as u32
+            );
+
+            let (received, err) =
+                _winapi_ReadFile(read as usize, 32, OptionalArg::Missing, vm).unwrap();
+            assert_eq!(err, 0);
+            let received = crate::builtins::bytes::PyBytesRef::try_from_object(vm, received)
+                .unwrap()
+                .to_vec();
+            assert_eq!(received, b"hello, pipe");
+
+            unsafe {
+                handleapi::CloseHandle(read);
+                handleapi::CloseHandle(write);
+            }
+        })
+    }
+
+    #[test]
+    fn open_process_token_reads_elevation() {
+        let process = unsafe { processthreadsapi::GetCurrentProcess() };
+        let mut token = null_mut();
+        let ret =
+            unsafe { processthreadsapi::OpenProcessToken(process, winnt::TOKEN_QUERY, &mut token) };
+        assert_ne!(ret, 0);
+
+        let mut size = 0u32;
+        unsafe {
+            securitybaseapi::GetTokenInformation(
+                token,
+                winnt::TokenElevation,
+                null_mut(),
+                0,
+                &mut size,
+            )
+        };
+        let mut buf = vec![0u8; size as usize];
+        let ret = unsafe {
+            securitybaseapi::GetTokenInformation(
+                token,
+                winnt::TokenElevation,
+                buf.as_mut_ptr() as _,
+                size,
+                &mut size,
+            )
+        };
+        assert_ne!(ret, 0);
+        unsafe { handleapi::CloseHandle(token) };
+    }
+
+    #[test]
+    fn create_file_opens_nul_for_writing() {
+        use crate::Interpreter;
+
+        Interpreter::default().enter(|vm| {
+            let name = vm.ctx.new_stringref("NUL".to_owned());
+            let handle = _winapi_CreateFile(
+                name,
+                winnt::GENERIC_WRITE,
+                winnt::FILE_SHARE_READ | winnt::FILE_SHARE_WRITE,
+                vm.ctx.none(),
+                fileapi::OPEN_EXISTING,
+                winnt::FILE_ATTRIBUTE_NORMAL,
+                OptionalArg::Missing,
+                vm,
+            )
+            .unwrap();
+            assert_ne!(handle as HANDLE, handleapi::INVALID_HANDLE_VALUE);
+            unsafe { handleapi::CloseHandle(handle as HANDLE) };
+        })
+    }
+}
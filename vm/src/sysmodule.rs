@@ -9,6 +9,7 @@ use crate::vm::{PySettings, VirtualMachine};
 use crate::{builtins, exceptions, py_io, version};
 use crate::{
     ItemProtocol, PyClassImpl, PyContext, PyObjectRef, PyRefExact, PyResult, PyStructSequence,
+    TryFromObject,
 };
 
 /*
@@ -151,9 +152,17 @@ fn sys_getrefcount(obj: PyObjectRef) -> usize {
     PyObjectRef::strong_count(&obj)
 }
 
-fn sys_getsizeof(obj: PyObjectRef) -> usize {
+fn sys_getsizeof(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<usize> {
     // TODO: implement default optional argument.
-    mem::size_of_val(&obj)
+    // Defer to the object's own `__sizeof__` when it defines one (e.g.
+    // `collections.OrderedDict`), matching CPython; fall back to the
+    // pointer-sized stub for everything else since this VM doesn't track
+    // per-object allocation sizes.
+    let sizeof = vm.get_attribute_opt(obj.clone(), "__sizeof__")?;
+    match sizeof {
+        Some(sizeof) => usize::try_from_object(vm, vm.invoke(&sizeof, ())?),
+        None => Ok(mem::size_of_val(&obj)),
+    }
 }
 
 fn sys_getfilesystemencoding(_vm: &VirtualMachine) -> String {
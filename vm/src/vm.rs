@@ -1711,6 +1711,10 @@ impl VirtualMachine {
         hash(obj, self)
     }
 
+    /// The `PyObject_Size` equivalent. Unlike CPython, this VM doesn't split
+    /// mapping and sequence length into separate slots -- both protocols
+    /// dispatch through the single `__len__` method, so a type that is both
+    /// a mapping and a sequence is sized correctly without a fallback.
     pub fn obj_len_opt(&self, obj: &PyObjectRef) -> Option<PyResult<usize>> {
         self.get_special_method(obj.clone(), "__len__")
             .map(Result::ok)
@@ -2039,6 +2043,7 @@ impl PyThread {
 mod tests {
     use super::Interpreter;
     use crate::builtins::{int, PyStr};
+    use crate::pyobject::{ItemProtocol, TypeProtocol};
     use num_bigint::ToBigInt;
 
     #[test]
@@ -2062,4 +2067,123 @@ mod tests {
             assert_eq!(value.as_ref(), "Hello Hello Hello Hello ")
         })
     }
+
+    #[test]
+    fn test_obj_len_works_for_sequence_and_mapping() {
+        Interpreter::default().enter(|vm| {
+            let list = vm.ctx.new_list(vec![vm.ctx.new_int(1), vm.ctx.new_int(2)]);
+            assert_eq!(vm.obj_len(&list).unwrap(), 2);
+
+            let dict = vm.ctx.new_dict();
+            dict.set_item("a", vm.ctx.new_int(1), vm).unwrap();
+            dict.set_item("b", vm.ctx.new_int(2), vm).unwrap();
+            assert_eq!(vm.obj_len(dict.as_object()).unwrap(), 2);
+        })
+    }
+
+    #[test]
+    fn test_extract_elements_large_tuple_matches_contents() {
+        // `extract_elements` already takes a direct-slice fast path for an
+        // exact `tuple`/`list` (see its match on `cls.is(...)` above) rather
+        // than going through the boxed `__iter__`/`__next__` protocol; this
+        // exercises that path at a size where a per-element allocation would
+        // show up, and just as importantly checks a large conversion still
+        // produces exactly the same elements in the same order.
+        Interpreter::default().enter(|vm| {
+            let elements: Vec<_> = (0..10_000).map(|i| vm.ctx.new_int(i)).collect();
+            let tuple = vm.ctx.new_tuple(elements.clone());
+            let extracted: Vec<_> = vm.extract_elements(&tuple).unwrap();
+            assert_eq!(extracted.len(), elements.len());
+            for (a, b) in extracted.iter().zip(elements.iter()) {
+                assert_eq!(int::get_value(a), int::get_value(b));
+            }
+        })
+    }
+
+    #[test]
+    fn test_extract_elements_generic_iterable_uses_length_hint_to_preallocate() {
+        // Anything other than an exact tuple/list falls through to
+        // `iterator::try_map`, which already preallocates its result `Vec`
+        // from `__length_hint__` (see `iterator::try_map`'s `Vec::with_capacity`
+        // call) rather than growing one element at a time; this just checks
+        // that path still produces the right elements in order for a custom
+        // iterable that reports an honest hint.
+        Interpreter::default().enter(|vm| {
+            let source = r#"
+class Countdown:
+    def __init__(self, n):
+        self.n = n
+
+    def __iter__(self):
+        return self
+
+    def __length_hint__(self):
+        return self.n
+
+    def __next__(self):
+        if self.n <= 0:
+            raise StopIteration
+        self.n -= 1
+        return self.n
+"#;
+            let code_obj = vm
+                .compile(source, crate::compile::Mode::Exec, "<test>".to_owned())
+                .unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code_obj, scope.clone()).unwrap();
+            let countdown_cls = scope.locals.as_object().get_item("Countdown", vm).unwrap();
+            let countdown = vm.invoke(&countdown_cls, (5,)).unwrap();
+
+            let extracted: Vec<_> = vm.extract_elements(&countdown).unwrap();
+            let values: Vec<_> = extracted.iter().map(int::get_value).cloned().collect();
+            assert_eq!(
+                values,
+                vec![
+                    4.to_bigint().unwrap(),
+                    3.to_bigint().unwrap(),
+                    2.to_bigint().unwrap(),
+                    1.to_bigint().unwrap(),
+                    0.to_bigint().unwrap()
+                ]
+            );
+        })
+    }
+
+    #[test]
+    fn test_call_send_drives_generator_like_yield_from() {
+        // `iterator::call_send` is what `YIELD_FROM` uses to delegate into a
+        // subiterator; this drives a plain generator through it directly
+        // from Rust, the same way, to check it forwards sent values into the
+        // generator's paused `yield` expression and still falls back to
+        // plain `next()` for the initial `None` send.
+        Interpreter::default().enter(|vm| {
+            let source = r#"
+def echo_twice():
+    first = yield
+    second = yield first
+    yield second
+"#;
+            let code_obj = vm
+                .compile(source, crate::compile::Mode::Exec, "<test>".to_owned())
+                .unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code_obj, scope.clone()).unwrap();
+            let echo_twice = scope.locals.as_object().get_item("echo_twice", vm).unwrap();
+            let gen = vm.invoke(&echo_twice, ()).unwrap();
+
+            let started = crate::iterator::call_send(vm, &gen, vm.ctx.none()).unwrap();
+            assert!(vm.is_none(&started));
+
+            let echoed =
+                crate::iterator::call_send(vm, &gen, vm.ctx.new_str("hello".to_owned())).unwrap();
+            assert_eq!(echoed.payload::<PyStr>().unwrap().as_ref(), "hello");
+
+            // sending a non-`None` value to a just-started generator is a
+            // `TypeError` in CPython; a fresh generator hits the same check.
+            let fresh = vm.invoke(&echo_twice, ()).unwrap();
+            let err = crate::iterator::call_send(vm, &fresh, vm.ctx.new_str("too soon".to_owned()))
+                .unwrap_err();
+            assert!(err.isinstance(&vm.ctx.exceptions.type_error));
+        })
+    }
 }